@@ -0,0 +1,980 @@
+use crate::client::{parse_json, parse_retry_after, status_error};
+use crate::diagnostics::redact;
+use crate::error::{ApiResult, Error};
+use crate::types::{RequestDetails, Severity};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, StreamExt};
+use reqwest::header::HeaderValue;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::time::Duration;
+
+/// The default base URL of the VictorOps alert ingestion endpoint.
+pub const DEFAULT_ALERT_BASE_URL: &str = "https://alert.victorops.com";
+
+/// HTTP client for sending alerts to the VictorOps REST integration endpoint.
+///
+/// Alert ingestion is authenticated by the REST integration key embedded in
+/// the URL rather than the API ID and key headers used by [`Client`](crate::Client),
+/// and is served from a different host.
+#[derive(Clone)]
+pub struct AlertClient {
+  base_url: String,
+  api_key: String,
+  http_client: reqwest::Client,
+}
+
+impl fmt::Debug for AlertClient {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("AlertClient")
+      .field("base_url", &self.base_url)
+      .field("api_key", &redact(&self.api_key))
+      .finish_non_exhaustive()
+  }
+}
+
+/// The kind of alert sent to the ingestion endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "UPPERCASE")]
+pub enum MessageType {
+  /// Opens an incident and pages the routing key's escalation policies.
+  Critical,
+  /// Records a warning on the timeline without paging.
+  Warning,
+  /// Acknowledges the incident for the entity.
+  Acknowledgement,
+  /// Records an informational message on the timeline.
+  Info,
+  /// Resolves the incident for the entity.
+  Recovery,
+}
+
+impl MessageType {
+  /// Classifies the message type with the same rules as incident entity states.
+  ///
+  /// `CRITICAL` and `WARNING` keep their severity; acknowledgements,
+  /// recoveries, and informational messages are [`Severity::Info`].
+  pub fn severity(&self) -> Severity {
+    match self {
+      MessageType::Critical => Severity::Critical,
+      MessageType::Warning => Severity::Warning,
+      MessageType::Acknowledgement | MessageType::Info | MessageType::Recovery => Severity::Info,
+    }
+  }
+}
+
+/// An alert payload for the REST integration endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AlertMessage {
+  /// The kind of alert.
+  pub message_type: MessageType,
+  /// Identifies the monitored entity so related alerts correlate into one incident.
+  pub entity_id: String,
+  /// A human-friendly name for the entity.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub entity_display_name: Option<String>,
+  /// A verbose description of the alert.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub state_message: Option<String>,
+  /// When the alert occurred, sent as seconds since the Unix epoch.
+  #[cfg(feature = "chrono")]
+  #[serde(
+    default,
+    skip_serializing_if = "Option::is_none",
+    with = "chrono::serde::ts_seconds_option"
+  )]
+  #[cfg_attr(feature = "schemars", schemars(with = "Option<i64>"))]
+  pub timestamp: Option<DateTime<Utc>>,
+  /// When the alert occurred, in seconds since the Unix epoch.
+  #[cfg(not(feature = "chrono"))]
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub timestamp: Option<i64>,
+  /// Additional fields, which VictorOps promotes into the alert data.
+  #[serde(flatten)]
+  pub extra: BTreeMap<String, Value>,
+}
+
+impl AlertMessage {
+  /// Creates an alert with only the required fields set.
+  ///
+  /// # Arguments
+  ///
+  /// * `message_type` - The kind of alert
+  /// * `entity_id` - Identifies the monitored entity
+  pub fn new(message_type: MessageType, entity_id: &str) -> Self {
+    AlertMessage {
+      message_type,
+      entity_id: entity_id.to_string(),
+      entity_display_name: None,
+      state_message: None,
+      timestamp: None,
+      extra: BTreeMap::new(),
+    }
+  }
+
+  /// Adds an arbitrary field to the alert.
+  ///
+  /// # Arguments
+  ///
+  /// * `key` - The field name
+  /// * `value` - The field value
+  pub fn with_field(mut self, key: &str, value: impl Into<Value>) -> Self {
+    self.extra.insert(key.to_string(), value.into());
+    self
+  }
+
+  /// Records who acknowledged the alert, for use with
+  /// [`MessageType::Acknowledgement`].
+  ///
+  /// # Arguments
+  ///
+  /// * `author` - The name of the user or system acknowledging the alert
+  pub fn with_ack_author(self, author: &str) -> Self {
+    self.with_field("ack_author", author)
+  }
+
+  /// Attaches a link annotation, shown in the incident as a clickable URL.
+  ///
+  /// # Arguments
+  ///
+  /// * `label` - The annotation label
+  /// * `url` - The URL the annotation links to
+  pub fn with_annotation_url(self, label: &str, url: &str) -> Self {
+    self.with_field(&format!("vo_annotate.u.{}", label), url)
+  }
+
+  /// Attaches a note annotation, shown in the incident as plain text.
+  ///
+  /// # Arguments
+  ///
+  /// * `label` - The annotation label
+  /// * `note` - The annotation text
+  pub fn with_annotation_note(self, label: &str, note: &str) -> Self {
+    self.with_field(&format!("vo_annotate.s.{}", label), note)
+  }
+
+  /// Returns the acknowledgement author, if one is set.
+  pub fn ack_author(&self) -> Option<&str> {
+    self.extra.get("ack_author").and_then(Value::as_str)
+  }
+}
+
+/// The maximum length of an entity ID accepted by VictorOps.
+pub const MAX_ENTITY_ID_LEN: usize = 255;
+
+/// A stable entity ID derived from the parts that identify a monitored check.
+///
+/// Repeated alerts with the same entity ID correlate into one incident, so
+/// the ID must be derived deterministically. Tags are sorted before hashing,
+/// so their order does not matter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EntityId(String);
+
+impl EntityId {
+  /// Derives an entity ID from a host, a check name, and identifying tags.
+  ///
+  /// Untagged IDs whose host and check contain no `:` and that fit within
+  /// [`MAX_ENTITY_ID_LEN`] are the readable `host:check`. Otherwise the
+  /// readable prefix is truncated as needed and suffixed with a 128-bit
+  /// FNV-1a hash of every part, so distinct inputs, including a host or check
+  /// that itself contains `:`, still yield distinct IDs. The hash is stable
+  /// across releases but is not cryptographic.
+  ///
+  /// # Arguments
+  ///
+  /// * `host` - The monitored host
+  /// * `check` - The name of the check
+  /// * `tags` - Key-value pairs that further identify the check
+  pub fn from_parts(host: &str, check: &str, tags: &[(&str, &str)]) -> Self {
+    let readable = format!("{}:{}", host, check);
+
+    let unambiguous = !host.contains(':') && !check.contains(':');
+    if tags.is_empty() && unambiguous && readable.len() <= MAX_ENTITY_ID_LEN {
+      return EntityId(readable);
+    }
+
+    let mut sorted_tags = tags.to_vec();
+    sorted_tags.sort();
+
+    let mut hasher = Fnv128::new();
+    hasher.write_part(host);
+    hasher.write_part(check);
+    for (key, value) in &sorted_tags {
+      hasher.write_part(key);
+      hasher.write_part(value);
+    }
+    let suffix = format!("{:032x}", hasher.finish());
+
+    let mut prefix_len = readable.len().min(MAX_ENTITY_ID_LEN - suffix.len() - 1);
+    while !readable.is_char_boundary(prefix_len) {
+      prefix_len -= 1;
+    }
+
+    EntityId(format!("{}:{}", &readable[..prefix_len], suffix))
+  }
+
+  /// Returns the entity ID as a string slice.
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
+
+impl fmt::Display for EntityId {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+impl AsRef<str> for EntityId {
+  fn as_ref(&self) -> &str {
+    &self.0
+  }
+}
+
+impl From<EntityId> for String {
+  fn from(id: EntityId) -> Self {
+    id.0
+  }
+}
+
+struct Fnv128(u128);
+
+impl Fnv128 {
+  const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+  const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+  fn new() -> Self {
+    Fnv128(Self::OFFSET_BASIS)
+  }
+
+  fn write(&mut self, bytes: &[u8]) {
+    for byte in bytes {
+      self.0 ^= u128::from(*byte);
+      self.0 = self.0.wrapping_mul(Self::PRIME);
+    }
+  }
+
+  fn write_part(&mut self, part: &str) {
+    self.write(&(part.len() as u64).to_le_bytes());
+    self.write(part.as_bytes());
+  }
+
+  fn finish(&self) -> u128 {
+    self.0
+  }
+}
+
+/// Strips the URL from a request error, since alert URLs embed the integration key.
+fn without_url(error: reqwest::Error) -> Error {
+  error.without_url().into()
+}
+
+/// Response from the alert ingestion endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct AlertResponse {
+  /// The outcome reported by VictorOps, either `success` or `failure`.
+  pub result: String,
+  /// The entity ID the alert was recorded against.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub entity_id: Option<String>,
+  /// A message describing a failure.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
+}
+
+impl AlertResponse {
+  /// Returns true if VictorOps accepted the alert.
+  pub fn is_success(&self) -> bool {
+    self.result == "success"
+  }
+}
+
+impl AlertClient {
+  /// Creates a new alert client with default timeout (30 seconds).
+  ///
+  /// # Arguments
+  ///
+  /// * `api_key` - The REST integration API key
+  /// * `base_url` - The base URL of the alert endpoint, usually [`DEFAULT_ALERT_BASE_URL`]
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use victorops::alerts::{AlertClient, DEFAULT_ALERT_BASE_URL};
+  ///
+  /// let client = AlertClient::new(
+  ///     "your-rest-integration-key".to_string(),
+  ///     DEFAULT_ALERT_BASE_URL.to_string(),
+  /// )?;
+  /// # Ok::<(), victorops::Error>(())
+  /// ```
+  pub fn new(api_key: impl Into<String>, base_url: impl Into<String>) -> ApiResult<Self> {
+    Self::with_timeout(api_key, base_url, Duration::from_secs(30))
+  }
+
+  /// Creates a new alert client with a custom timeout.
+  ///
+  /// # Arguments
+  ///
+  /// * `api_key` - The REST integration API key
+  /// * `base_url` - The base URL of the alert endpoint
+  /// * `timeout` - Custom timeout duration for HTTP requests
+  ///
+  /// # Returns
+  ///
+  /// The client, or `Error::InvalidInput` if `api_key` is empty.
+  pub fn with_timeout(
+    api_key: impl Into<String>,
+    base_url: impl Into<String>,
+    timeout: Duration,
+  ) -> ApiResult<Self> {
+    let api_key = api_key.into();
+    if api_key.is_empty() {
+      return Err(Error::InvalidInput(
+        "REST integration API key cannot be empty".to_string(),
+      ));
+    }
+
+    let http_client = reqwest::Client::builder().timeout(timeout).build()?;
+
+    Ok(AlertClient {
+      base_url: base_url.into(),
+      api_key,
+      http_client,
+    })
+  }
+
+  /// Sends an alert to the given routing key.
+  ///
+  /// The payload is usually an [`AlertMessage`], but any serializable body is
+  /// accepted for integrations that send their own fields.
+  ///
+  /// # Arguments
+  ///
+  /// * `routing_key` - The routing key that determines who is paged
+  /// * `payload` - The alert body
+  ///
+  /// # Returns
+  ///
+  /// A tuple containing the ingestion response and request details.
+  pub async fn send_alert<T: Serialize + ?Sized>(
+    &self,
+    routing_key: &str,
+    payload: &T,
+  ) -> ApiResult<(AlertResponse, RequestDetails)> {
+    let encoded_routing_key =
+      url::form_urlencoded::byte_serialize(routing_key.as_bytes()).collect::<String>();
+    let url = format!(
+      "{}/integrations/generic/20131114/alert/{}/{}",
+      self.base_url, self.api_key, encoded_routing_key
+    );
+    let request_body = serde_json::to_string(payload)?;
+
+    let request = self
+      .http_client
+      .post(&url)
+      .header("Content-Type", HeaderValue::from_static("application/json"))
+      .body(request_body.clone())
+      .build()
+      .map_err(without_url)?;
+    let request_headers = request.headers().clone();
+
+    let started = std::time::Instant::now();
+    let response = self
+      .http_client
+      .execute(request)
+      .await
+      .map_err(without_url)?;
+    let status_code = response.status().as_u16();
+    let response_headers = response.headers().clone();
+    let response_bytes = response.bytes().await.map_err(without_url)?;
+
+    if status_code == 429 {
+      return Err(Error::RateLimited {
+        retry_after: parse_retry_after(&response_headers),
+      });
+    }
+
+    if status_code >= 400 {
+      let endpoint = format!(
+        "/integrations/generic/20131114/alert/{}/{}",
+        redact(&self.api_key),
+        encoded_routing_key
+      );
+      return Err(status_error(
+        endpoint,
+        status_code,
+        &response_headers,
+        &response_bytes,
+      ));
+    }
+
+    let alert_response: AlertResponse = parse_json(&response_bytes)?;
+    Ok((
+      alert_response,
+      RequestDetails {
+        status_code,
+        response_bytes,
+        request_body,
+        method: reqwest::Method::POST,
+        url: url.replace(&self.api_key, &redact(&self.api_key)),
+        request_headers,
+        response_headers,
+        elapsed: started.elapsed(),
+        ..Default::default()
+      },
+    ))
+  }
+
+  /// Resolves the incident for an entity.
+  ///
+  /// # Arguments
+  ///
+  /// * `routing_key` - The routing key the original alert was sent to
+  /// * `entity_id` - The entity whose incident should be resolved
+  ///
+  /// # Returns
+  ///
+  /// A tuple containing the ingestion response and request details.
+  pub async fn send_recovery(
+    &self,
+    routing_key: &str,
+    entity_id: &str,
+  ) -> ApiResult<(AlertResponse, RequestDetails)> {
+    let message = AlertMessage::new(MessageType::Recovery, entity_id);
+    self.send_alert(routing_key, &message).await
+  }
+
+  /// Acknowledges the incident for an entity.
+  ///
+  /// # Arguments
+  ///
+  /// * `routing_key` - The routing key the original alert was sent to
+  /// * `entity_id` - The entity whose incident should be acknowledged
+  /// * `author` - The name of the user or system acknowledging the incident
+  ///
+  /// # Returns
+  ///
+  /// A tuple containing the ingestion response and request details.
+  pub async fn send_ack(
+    &self,
+    routing_key: &str,
+    entity_id: &str,
+    author: &str,
+  ) -> ApiResult<(AlertResponse, RequestDetails)> {
+    let message =
+      AlertMessage::new(MessageType::Acknowledgement, entity_id).with_ack_author(author);
+    self.send_alert(routing_key, &message).await
+  }
+
+  /// Sends a batch of alerts with at most `concurrency` requests in flight.
+  ///
+  /// Every alert is attempted even if others fail, so exporters can retry or
+  /// log individual failures.
+  ///
+  /// # Arguments
+  ///
+  /// * `alerts` - Pairs of routing key and alert to send
+  /// * `concurrency` - The maximum number of alert requests in flight
+  ///
+  /// # Returns
+  ///
+  /// The result of each alert, in the same order as `alerts`.
+  pub async fn send_alerts<I>(
+    &self,
+    alerts: I,
+    concurrency: usize,
+  ) -> Vec<ApiResult<(AlertResponse, RequestDetails)>>
+  where
+    I: IntoIterator<Item = (String, AlertMessage)>,
+  {
+    stream::iter(alerts)
+      .map(|(routing_key, message)| async move { self.send_alert(&routing_key, &message).await })
+      .buffered(concurrency.max(1))
+      .collect()
+      .await
+  }
+
+  /// Sends the same alert to several routing keys with at most `concurrency`
+  /// requests in flight.
+  ///
+  /// Every key is attempted even if others fail, so a service that must page
+  /// several teams can tell exactly which pages went out. Duplicate keys are
+  /// sent once.
+  ///
+  /// # Arguments
+  ///
+  /// * `routing_keys` - The routing keys to send the alert to
+  /// * `payload` - The alert body
+  /// * `concurrency` - The maximum number of alert requests in flight
+  ///
+  /// # Returns
+  ///
+  /// The result for each routing key, keyed by routing key.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # async fn run(alerts: victorops::alerts::AlertClient) {
+  /// use victorops::alerts::{AlertMessage, MessageType};
+  ///
+  /// let message = AlertMessage::new(MessageType::Critical, "db-1/disk");
+  /// for (routing_key, result) in alerts.send_to_many(&["database", "storage"], &message, 4).await {
+  ///   if let Err(error) = result {
+  ///     eprintln!("failed to page {}: {}", routing_key, error);
+  ///   }
+  /// }
+  /// # }
+  /// ```
+  pub async fn send_to_many(
+    &self,
+    routing_keys: &[&str],
+    payload: &AlertMessage,
+    concurrency: usize,
+  ) -> BTreeMap<String, ApiResult<(AlertResponse, RequestDetails)>> {
+    let unique: BTreeSet<&str> = routing_keys.iter().copied().collect();
+    let concurrency = concurrency.min(unique.len()).max(1);
+
+    stream::iter(unique)
+      .map(|routing_key| async move {
+        (
+          routing_key.to_string(),
+          self.send_alert(routing_key, payload).await,
+        )
+      })
+      .buffered(concurrency)
+      .collect()
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[tokio::test]
+  async fn test_send_alert_success() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+      .mock(
+        "POST",
+        "/integrations/generic/20131114/alert/rest-key/database",
+      )
+      .match_header("content-type", "application/json")
+      .match_body(mockito::Matcher::Json(
+        json!({"message_type": "CRITICAL", "entity_id": "db-1"}),
+      ))
+      .with_status(200)
+      .with_header("content-type", "application/json")
+      .with_body(r#"{"result": "success", "entity_id": "db-1"}"#)
+      .create_async()
+      .await;
+
+    let client = AlertClient::new("rest-key".to_string(), server.url()).unwrap();
+
+    let (response, details) = client
+      .send_alert(
+        "database",
+        &json!({"message_type": "CRITICAL", "entity_id": "db-1"}),
+      )
+      .await
+      .unwrap();
+
+    assert!(response.is_success());
+    assert_eq!(response.entity_id, Some("db-1".to_string()));
+    assert_eq!(details.status_code, 200);
+    assert_eq!(details.method, reqwest::Method::POST);
+    assert_eq!(
+      details.url,
+      format!(
+        "{}/integrations/generic/20131114/alert/****/database",
+        server.url()
+      )
+    );
+    mock.assert_async().await;
+  }
+
+  #[test]
+  fn test_new_rejects_empty_api_key() {
+    assert!(matches!(
+      AlertClient::new("", DEFAULT_ALERT_BASE_URL),
+      Err(Error::InvalidInput(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_send_alert_error_status() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+      .mock(
+        "POST",
+        "/integrations/generic/20131114/alert/bad-key/database",
+      )
+      .with_status(401)
+      .with_body(r#"{"result": "failure", "message": "Invalid API key"}"#)
+      .create_async()
+      .await;
+
+    let client = AlertClient::new("bad-key".to_string(), server.url()).unwrap();
+
+    let result = client
+      .send_alert("database", &json!({"message_type": "INFO"}))
+      .await;
+
+    assert!(matches!(result, Err(Error::Api { status: 401, .. })));
+    mock.assert_async().await;
+
+    let conflict_mock = server
+      .mock(
+        "POST",
+        "/integrations/generic/20131114/alert/bad-key/storage",
+      )
+      .with_status(409)
+      .with_body(r#"{"result": "failure"}"#)
+      .create_async()
+      .await;
+
+    let result = client
+      .send_alert("storage", &json!({"message_type": "INFO"}))
+      .await;
+
+    assert!(matches!(result, Err(Error::Conflict { .. })));
+    conflict_mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_send_alert_rate_limited() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+      .mock(
+        "POST",
+        "/integrations/generic/20131114/alert/rest-key/database",
+      )
+      .with_status(429)
+      .with_header("retry-after", "15")
+      .create_async()
+      .await;
+
+    let client = AlertClient::new("rest-key".to_string(), server.url()).unwrap();
+
+    let result = client
+      .send_alert("database", &json!({"message_type": "INFO"}))
+      .await;
+
+    assert!(matches!(
+      result,
+      Err(Error::RateLimited {
+        retry_after: Some(retry_after)
+      }) if retry_after == Duration::from_secs(15)
+    ));
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_send_alerts_reports_each_result() {
+    let mut server = mockito::Server::new_async().await;
+
+    let ok_mock = server
+      .mock(
+        "POST",
+        "/integrations/generic/20131114/alert/rest-key/database",
+      )
+      .with_status(200)
+      .with_header("content-type", "application/json")
+      .with_body(r#"{"result": "success"}"#)
+      .expect(2)
+      .create_async()
+      .await;
+
+    let failing_mock = server
+      .mock(
+        "POST",
+        "/integrations/generic/20131114/alert/rest-key/unknown",
+      )
+      .with_status(404)
+      .with_body(r#"{"result": "failure"}"#)
+      .create_async()
+      .await;
+
+    let client = AlertClient::new("rest-key".to_string(), server.url()).unwrap();
+
+    let results = client
+      .send_alerts(
+        vec![
+          (
+            "database".to_string(),
+            AlertMessage::new(MessageType::Critical, "db-1"),
+          ),
+          (
+            "unknown".to_string(),
+            AlertMessage::new(MessageType::Critical, "web-1"),
+          ),
+          (
+            "database".to_string(),
+            AlertMessage::new(MessageType::Recovery, "db-2"),
+          ),
+        ],
+        2,
+      )
+      .await;
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(matches!(results[1], Err(Error::NotFound { .. })));
+    assert!(results[2].is_ok());
+
+    ok_mock.assert_async().await;
+    failing_mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_send_to_many_reports_each_key() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mut ok_mocks = Vec::new();
+    for routing_key in ["database", "storage"] {
+      ok_mocks.push(
+        server
+          .mock(
+            "POST",
+            format!(
+              "/integrations/generic/20131114/alert/rest-key/{}",
+              routing_key
+            )
+            .as_str(),
+          )
+          .match_body(mockito::Matcher::Json(
+            json!({"message_type": "CRITICAL", "entity_id": "db-1"}),
+          ))
+          .with_status(200)
+          .with_body(r#"{"result": "success", "entity_id": "db-1"}"#)
+          .create_async()
+          .await,
+      );
+    }
+
+    let failing_mock = server
+      .mock(
+        "POST",
+        "/integrations/generic/20131114/alert/rest-key/unknown",
+      )
+      .with_status(404)
+      .with_body(r#"{"result": "failure"}"#)
+      .create_async()
+      .await;
+
+    let client = AlertClient::new("rest-key".to_string(), server.url()).unwrap();
+    let message = AlertMessage::new(MessageType::Critical, "db-1");
+
+    let results = client
+      .send_to_many(&["database", "unknown", "storage", "database"], &message, 2)
+      .await;
+
+    assert_eq!(
+      results.keys().map(String::as_str).collect::<Vec<_>>(),
+      ["database", "storage", "unknown"]
+    );
+    assert!(results["database"].as_ref().unwrap().0.is_success());
+    assert!(results["storage"].as_ref().unwrap().0.is_success());
+    assert!(matches!(
+      &results["unknown"],
+      Err(Error::NotFound { endpoint, .. }) if !endpoint.contains("rest-key")
+    ));
+
+    for mock in ok_mocks {
+      mock.assert_async().await;
+    }
+    failing_mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_send_recovery_and_ack() {
+    let mut server = mockito::Server::new_async().await;
+
+    let recovery_mock = server
+      .mock(
+        "POST",
+        "/integrations/generic/20131114/alert/rest-key/database",
+      )
+      .match_body(mockito::Matcher::Json(
+        json!({"message_type": "RECOVERY", "entity_id": "db-1"}),
+      ))
+      .with_status(200)
+      .with_body(r#"{"result": "success", "entity_id": "db-1"}"#)
+      .create_async()
+      .await;
+
+    let ack_mock = server
+      .mock(
+        "POST",
+        "/integrations/generic/20131114/alert/rest-key/database",
+      )
+      .match_body(mockito::Matcher::Json(
+        json!({"message_type": "ACKNOWLEDGEMENT", "entity_id": "db-1", "ack_author": "jdoe"}),
+      ))
+      .with_status(200)
+      .with_body(r#"{"result": "success", "entity_id": "db-1"}"#)
+      .create_async()
+      .await;
+
+    let client = AlertClient::new("rest-key".to_string(), server.url()).unwrap();
+
+    let (response, _) = client.send_ack("database", "db-1", "jdoe").await.unwrap();
+    assert!(response.is_success());
+
+    let (response, _) = client.send_recovery("database", "db-1").await.unwrap();
+    assert!(response.is_success());
+
+    recovery_mock.assert_async().await;
+    ack_mock.assert_async().await;
+  }
+
+  #[test]
+  fn test_entity_id_from_parts() {
+    let plain = EntityId::from_parts("db-1", "disk", &[]);
+    assert_eq!(plain.as_str(), "db-1:disk");
+
+    let tagged = EntityId::from_parts("db-1", "disk", &[("mount", "/var"), ("env", "prod")]);
+    let reordered = EntityId::from_parts("db-1", "disk", &[("env", "prod"), ("mount", "/var")]);
+    assert_eq!(tagged, reordered);
+    assert!(tagged.as_str().starts_with("db-1:disk:"));
+    assert_eq!(tagged.as_str().len(), "db-1:disk:".len() + 32);
+
+    let other = EntityId::from_parts("db-1", "disk", &[("mount", "/home"), ("env", "prod")]);
+    assert_ne!(tagged, other);
+
+    let ambiguous_a = EntityId::from_parts("a", "b", &[("c", "d")]);
+    let ambiguous_b = EntityId::from_parts("a", "b", &[("cd", "")]);
+    assert_ne!(ambiguous_a, ambiguous_b);
+
+    let colon_host = EntityId::from_parts("a:b", "c", &[]);
+    let colon_check = EntityId::from_parts("a", "b:c", &[]);
+    assert_ne!(colon_host, colon_check);
+    assert!(colon_host.as_str().starts_with("a:b:c:"));
+    assert_eq!(colon_host.as_str().len(), "a:b:c:".len() + 32);
+  }
+
+  #[test]
+  fn test_entity_id_length_limit() {
+    let host = "h".repeat(300);
+    let long = EntityId::from_parts(&host, "disk", &[]);
+    assert_eq!(long.as_str().len(), MAX_ENTITY_ID_LEN);
+
+    let longer = EntityId::from_parts(&host, "memory", &[]);
+    assert_ne!(long, longer);
+
+    let multibyte = EntityId::from_parts(&"é".repeat(200), "disk", &[]);
+    assert!(multibyte.as_str().len() <= MAX_ENTITY_ID_LEN);
+  }
+
+  #[tokio::test]
+  async fn test_connect_error_omits_api_key() {
+    let client = AlertClient::new("super-secret-rest-key", "http://127.0.0.1:1").unwrap();
+
+    let error = client
+      .send_alert("database", &json!({"message_type": "INFO"}))
+      .await
+      .unwrap_err();
+
+    assert!(matches!(error, Error::Connect(_)));
+    assert!(!error.to_string().contains("super-secret-rest-key"));
+    assert!(!format!("{:?}", error).contains("super-secret-rest-key"));
+  }
+
+  #[test]
+  fn test_debug_redacts_api_key() {
+    let client = AlertClient::new(
+      "super-secret-rest-key".to_string(),
+      DEFAULT_ALERT_BASE_URL.to_string(),
+    )
+    .unwrap();
+
+    let debug = format!("{:?}", client);
+    assert!(!debug.contains("super-secret-rest-key"));
+    assert!(debug.contains("****-key"));
+  }
+
+  #[test]
+  fn test_alert_message_serialization() {
+    let mut message = AlertMessage::new(MessageType::Critical, "db-1/disk");
+    message.entity_display_name = Some("db-1 disk full".to_string());
+    message.state_message = Some("/var is 98% full".to_string());
+    #[cfg(feature = "chrono")]
+    {
+      message.timestamp = Some("2024-01-01T00:00:00Z".parse().unwrap());
+    }
+    #[cfg(not(feature = "chrono"))]
+    {
+      message.timestamp = Some(1704067200);
+    }
+
+    assert_eq!(
+      serde_json::to_value(&message).unwrap(),
+      json!({
+        "message_type": "CRITICAL",
+        "entity_id": "db-1/disk",
+        "entity_display_name": "db-1 disk full",
+        "state_message": "/var is 98% full",
+        "timestamp": 1704067200
+      })
+    );
+
+    let minimal = AlertMessage::new(MessageType::Acknowledgement, "db-1/disk");
+    assert_eq!(
+      serde_json::to_value(&minimal).unwrap(),
+      json!({"message_type": "ACKNOWLEDGEMENT", "entity_id": "db-1/disk"})
+    );
+
+    let parsed: AlertMessage =
+      serde_json::from_str(r#"{"message_type": "RECOVERY", "entity_id": "db-1/disk"}"#).unwrap();
+    assert_eq!(parsed.message_type, MessageType::Recovery);
+    assert!(parsed.timestamp.is_none());
+    assert_eq!(parsed.message_type.severity(), Severity::Info);
+    assert_eq!(message.message_type.severity().priority(), "P1");
+  }
+
+  #[test]
+  fn test_alert_message_extra_fields() {
+    let message = AlertMessage::new(MessageType::Acknowledgement, "db-1/disk")
+      .with_ack_author("jdoe")
+      .with_annotation_url("Runbook", "https://runbooks.example.com/disk")
+      .with_annotation_note("Owner", "storage team")
+      .with_field("host", "db-1");
+
+    assert_eq!(message.ack_author(), Some("jdoe"));
+    assert_eq!(
+      serde_json::to_value(&message).unwrap(),
+      json!({
+        "message_type": "ACKNOWLEDGEMENT",
+        "entity_id": "db-1/disk",
+        "ack_author": "jdoe",
+        "vo_annotate.u.Runbook": "https://runbooks.example.com/disk",
+        "vo_annotate.s.Owner": "storage team",
+        "host": "db-1"
+      })
+    );
+    assert_eq!(
+      serde_json::to_string(&message).unwrap(),
+      r#"{"message_type":"ACKNOWLEDGEMENT","entity_id":"db-1/disk","ack_author":"jdoe","host":"db-1","vo_annotate.s.Owner":"storage team","vo_annotate.u.Runbook":"https://runbooks.example.com/disk"}"#
+    );
+
+    let parsed: AlertMessage = serde_json::from_str(
+      r#"{"message_type": "CRITICAL", "entity_id": "db-1/disk", "timestamp": 1704067200, "region": "us-east-1"}"#,
+    )
+    .unwrap();
+    assert_eq!(parsed.extra.get("region"), Some(&json!("us-east-1")));
+    assert!(parsed.timestamp.is_some());
+    assert!(!parsed.extra.contains_key("timestamp"));
+  }
+}
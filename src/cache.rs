@@ -0,0 +1,134 @@
+use crate::types::RequestDetails;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A class of read endpoints that share a cache time-to-live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum CacheClass {
+  /// `get_all_teams`.
+  Teams,
+  /// `get_all_routing_keys`.
+  RoutingKeys,
+  /// `get_all_users` and `get_all_users_v2`.
+  Users,
+}
+
+/// Time-to-live settings for the opt-in response cache.
+///
+/// Only endpoint classes with a configured TTL are cached.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheConfig {
+  ttls: HashMap<CacheClass, Duration>,
+}
+
+impl CacheConfig {
+  /// Creates a configuration that caches nothing.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Caches responses of an endpoint class for `ttl`.
+  ///
+  /// # Arguments
+  ///
+  /// * `class` - The endpoint class to cache
+  /// * `ttl` - How long a cached response stays fresh
+  pub fn ttl(mut self, class: CacheClass, ttl: Duration) -> Self {
+    self.ttls.insert(class, ttl);
+    self
+  }
+
+  /// Returns the configured TTL of an endpoint class, if it is cached.
+  pub fn ttl_for(&self, class: CacheClass) -> Option<Duration> {
+    self.ttls.get(&class).copied()
+  }
+
+  pub(crate) fn ttls(&self) -> impl Iterator<Item = (CacheClass, Duration)> + '_ {
+    self.ttls.iter().map(|(class, ttl)| (*class, *ttl))
+  }
+}
+
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+  config: CacheConfig,
+  entries: Mutex<HashMap<String, (Instant, RequestDetails)>>,
+}
+
+impl ResponseCache {
+  pub(crate) fn new(config: CacheConfig) -> Self {
+    ResponseCache {
+      config,
+      entries: Mutex::new(HashMap::new()),
+    }
+  }
+
+  pub(crate) fn config(&self) -> &CacheConfig {
+    &self.config
+  }
+
+  pub(crate) fn get(&self, endpoint: &str, ttl: Duration) -> Option<RequestDetails> {
+    let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+    entries
+      .get(endpoint)
+      .filter(|(stored_at, _)| stored_at.elapsed() < ttl)
+      .map(|(_, details)| details.clone())
+  }
+
+  pub(crate) fn insert(&self, endpoint: &str, details: RequestDetails) {
+    let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+    entries.insert(endpoint.to_string(), (Instant::now(), details));
+  }
+
+  pub(crate) fn clear(&self) {
+    self
+      .entries
+      .lock()
+      .unwrap_or_else(|e| e.into_inner())
+      .clear();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn details(body: &str) -> RequestDetails {
+    RequestDetails {
+      status_code: 200,
+      response_bytes: bytes::Bytes::copy_from_slice(body.as_bytes()),
+      request_body: "{}".to_string(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_config_ttls() {
+    let config = CacheConfig::new().ttl(CacheClass::Teams, Duration::from_secs(30));
+    assert_eq!(
+      config.ttl_for(CacheClass::Teams),
+      Some(Duration::from_secs(30))
+    );
+    assert_eq!(config.ttl_for(CacheClass::Users), None);
+  }
+
+  #[test]
+  fn test_entries_expire_and_clear() {
+    let cache = ResponseCache::new(CacheConfig::new());
+    cache.insert("v1/team", details("[]"));
+
+    assert_eq!(
+      cache
+        .get("v1/team", Duration::from_secs(60))
+        .map(|d| d.response_body().into_owned()),
+      Some("[]".to_string())
+    );
+    assert!(cache.get("v1/team", Duration::ZERO).is_none());
+    assert!(cache.get("v1/user", Duration::from_secs(60)).is_none());
+
+    cache.clear();
+    assert!(cache.get("v1/team", Duration::from_secs(60)).is_none());
+  }
+}
@@ -0,0 +1,200 @@
+use crate::diagnostics::redact;
+use crate::error::ApiResult;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// A VictorOps API ID and key pair.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Credentials {
+  /// The VictorOps API ID.
+  pub api_id: String,
+  /// The VictorOps API key.
+  pub api_key: String,
+}
+
+impl Credentials {
+  /// Creates a credentials pair.
+  ///
+  /// # Arguments
+  ///
+  /// * `api_id` - The VictorOps API ID for authentication
+  /// * `api_key` - The VictorOps API key for authentication
+  pub fn new(api_id: impl Into<String>, api_key: impl Into<String>) -> Self {
+    Credentials {
+      api_id: api_id.into(),
+      api_key: api_key.into(),
+    }
+  }
+}
+
+impl fmt::Debug for Credentials {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Credentials")
+      .field("api_id", &redact(&self.api_id))
+      .field("api_key", &redact(&self.api_key))
+      .finish()
+  }
+}
+
+/// A boxed future returned by [`CredentialsProvider::get`].
+pub type CredentialsFuture<'a> = Pin<Box<dyn Future<Output = ApiResult<Credentials>> + Send + 'a>>;
+
+/// A source of VictorOps API credentials.
+///
+/// The client asks its provider for credentials before every request, so
+/// providers backed by a secrets store can hand out fresh keys without the
+/// client being rebuilt. Wrap slow providers in [`RefreshingCredentials`] to
+/// only consult them on an interval.
+pub trait CredentialsProvider: fmt::Debug + Send + Sync {
+  /// Returns the current credentials.
+  fn get(&self) -> CredentialsFuture<'_>;
+}
+
+/// Credentials that never change.
+///
+/// This is the provider used by [`Client::new`](crate::Client::new) and
+/// [`Client::with_timeout`](crate::Client::with_timeout).
+#[derive(Clone)]
+pub struct StaticCredentials {
+  credentials: Credentials,
+}
+
+impl StaticCredentials {
+  /// Creates a provider that always returns the given credentials.
+  ///
+  /// # Arguments
+  ///
+  /// * `api_id` - The VictorOps API ID for authentication
+  /// * `api_key` - The VictorOps API key for authentication
+  pub fn new(api_id: impl Into<String>, api_key: impl Into<String>) -> Self {
+    StaticCredentials {
+      credentials: Credentials::new(api_id, api_key),
+    }
+  }
+}
+
+impl fmt::Debug for StaticCredentials {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("StaticCredentials")
+      .field("api_id", &redact(&self.credentials.api_id))
+      .field("api_key", &redact(&self.credentials.api_key))
+      .finish()
+  }
+}
+
+impl CredentialsProvider for StaticCredentials {
+  fn get(&self) -> CredentialsFuture<'_> {
+    Box::pin(async move { Ok(self.credentials.clone()) })
+  }
+}
+
+/// Caches another provider's credentials and refreshes them on an interval.
+pub struct RefreshingCredentials<P> {
+  inner: P,
+  interval: Duration,
+  cached: Mutex<Option<(Credentials, Instant)>>,
+}
+
+impl<P: fmt::Debug> fmt::Debug for RefreshingCredentials<P> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("RefreshingCredentials")
+      .field("inner", &self.inner)
+      .field("interval", &self.interval)
+      .finish_non_exhaustive()
+  }
+}
+
+impl<P: CredentialsProvider> RefreshingCredentials<P> {
+  /// Wraps a provider so it is consulted at most once per `interval`.
+  ///
+  /// # Arguments
+  ///
+  /// * `inner` - The provider to fetch credentials from
+  /// * `interval` - How long fetched credentials are reused before refreshing
+  pub fn new(inner: P, interval: Duration) -> Self {
+    RefreshingCredentials {
+      inner,
+      interval,
+      cached: Mutex::new(None),
+    }
+  }
+}
+
+impl<P: CredentialsProvider> CredentialsProvider for RefreshingCredentials<P> {
+  fn get(&self) -> CredentialsFuture<'_> {
+    Box::pin(async move {
+      let mut cached = self.cached.lock().await;
+
+      if let Some((credentials, fetched_at)) = cached.as_ref()
+        && fetched_at.elapsed() < self.interval
+      {
+        return Ok(credentials.clone());
+      }
+
+      let credentials = self.inner.get().await?;
+      *cached = Some((credentials.clone(), Instant::now()));
+      Ok(credentials)
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  #[derive(Debug, Default)]
+  struct CountingProvider {
+    calls: AtomicUsize,
+  }
+
+  impl CredentialsProvider for CountingProvider {
+    fn get(&self) -> CredentialsFuture<'_> {
+      Box::pin(async move {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(Credentials::new(
+          format!("id-{}", call),
+          format!("key-{}", call),
+        ))
+      })
+    }
+  }
+
+  #[tokio::test]
+  async fn test_static_credentials() {
+    let provider = StaticCredentials::new("my-api-id".to_string(), "my-secret-key".to_string());
+
+    assert_eq!(
+      provider.get().await.unwrap(),
+      Credentials::new("my-api-id".to_string(), "my-secret-key".to_string())
+    );
+
+    let debug = format!("{:?}", provider);
+    assert!(!debug.contains("my-secret-key"));
+    assert!(debug.contains("****-key"));
+
+    let credentials = provider.get().await.unwrap();
+    assert!(!format!("{:?}", credentials).contains("my-secret-key"));
+  }
+
+  #[tokio::test]
+  async fn test_refreshing_credentials_caches_within_interval() {
+    let provider = RefreshingCredentials::new(CountingProvider::default(), Duration::from_secs(60));
+
+    assert_eq!(provider.get().await.unwrap().api_id, "id-0");
+    assert_eq!(provider.get().await.unwrap().api_id, "id-0");
+    assert_eq!(provider.inner.calls.load(Ordering::SeqCst), 1);
+    assert!(!format!("{:?}", provider).contains("key-0"));
+  }
+
+  #[tokio::test]
+  async fn test_refreshing_credentials_refreshes_after_interval() {
+    let provider = RefreshingCredentials::new(CountingProvider::default(), Duration::ZERO);
+
+    assert_eq!(provider.get().await.unwrap().api_id, "id-0");
+    assert_eq!(provider.get().await.unwrap().api_id, "id-1");
+  }
+}
@@ -0,0 +1,155 @@
+use crate::cache::CacheClass;
+use crate::client::RateLimitPolicy;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Counters of requests made by a client and the errors they produced.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RequestCounters {
+  /// The total number of requests sent.
+  pub requests: u64,
+  /// Requests that failed before a response was received.
+  pub transport_errors: u64,
+  /// Requests that received a 4xx response.
+  pub client_errors: u64,
+  /// Requests that received a 5xx response.
+  pub server_errors: u64,
+}
+
+/// A redacted snapshot of a client's effective configuration and counters.
+///
+/// Credentials are masked so the snapshot can be included in support bundles.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DebugInfo {
+  /// The base URL for the VictorOps public API.
+  pub base_url: String,
+  /// A redacted description of the credentials provider.
+  pub credentials: String,
+  /// The timeout applied to each HTTP request.
+  pub timeout: Duration,
+  /// The maximum response body size, if one is configured.
+  pub max_response_size: Option<usize>,
+  /// The outbound proxy URL, if one is configured.
+  pub proxy: Option<String>,
+  /// How `429 Too Many Requests` responses are retried.
+  pub rate_limit_policy: RateLimitPolicy,
+  /// How long a repeated write with the same idempotency key is answered from
+  /// the client's record.
+  pub idempotency_window: Duration,
+  /// Whether response fields with unexpected nulls or shapes are dropped
+  /// instead of failing the parse.
+  pub lenient_parsing: bool,
+  /// Whether formatted phone numbers are normalized to E.164 on contact
+  /// creation.
+  pub lenient_phone_numbers: bool,
+  /// The response cache TTL of each cached endpoint class.
+  pub cache_ttls: BTreeMap<CacheClass, Duration>,
+  /// The cargo features the crate was built with.
+  pub features: Vec<&'static str>,
+  /// The request and error counters accumulated by the client.
+  pub counters: RequestCounters,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Metrics {
+  requests: AtomicU64,
+  transport_errors: AtomicU64,
+  client_errors: AtomicU64,
+  server_errors: AtomicU64,
+}
+
+impl Metrics {
+  pub(crate) fn record_request(&self) {
+    self.requests.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub(crate) fn record_transport_error(&self) {
+    self.transport_errors.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub(crate) fn record_status(&self, status_code: u16) {
+    match status_code {
+      400..=499 => self.client_errors.fetch_add(1, Ordering::Relaxed),
+      500..=599 => self.server_errors.fetch_add(1, Ordering::Relaxed),
+      _ => return,
+    };
+  }
+
+  pub(crate) fn snapshot(&self) -> RequestCounters {
+    RequestCounters {
+      requests: self.requests.load(Ordering::Relaxed),
+      transport_errors: self.transport_errors.load(Ordering::Relaxed),
+      client_errors: self.client_errors.load(Ordering::Relaxed),
+      server_errors: self.server_errors.load(Ordering::Relaxed),
+    }
+  }
+}
+
+/// Returns the names of the crate's cargo features that are enabled.
+pub(crate) fn enabled_features() -> Vec<&'static str> {
+  [
+    ("axum", cfg!(feature = "axum")),
+    ("chrono", cfg!(feature = "chrono")),
+    ("native-tls", cfg!(feature = "native-tls")),
+    ("rmp-serde", cfg!(feature = "rmp-serde")),
+    ("rustls-tls", cfg!(feature = "rustls-tls")),
+    ("schemars", cfg!(feature = "schemars")),
+    ("serialize-errors", cfg!(feature = "serialize-errors")),
+    ("test-util", cfg!(feature = "test-util")),
+    ("toml", cfg!(feature = "toml")),
+  ]
+  .into_iter()
+  .filter_map(|(name, enabled)| enabled.then_some(name))
+  .collect()
+}
+
+/// Masks a secret, keeping only its last four characters when it is long
+/// enough for that to be safe.
+pub(crate) fn redact(secret: &str) -> String {
+  let chars: Vec<char> = secret.chars().collect();
+
+  if chars.len() > 8 {
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("****{}", tail)
+  } else {
+    "****".to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_redact() {
+    assert_eq!(redact("abcdefghijkl"), "****ijkl");
+    assert_eq!(redact("short"), "****");
+    assert_eq!(redact(""), "****");
+  }
+
+  #[test]
+  fn test_metrics_snapshot() {
+    let metrics = Metrics::default();
+    metrics.record_request();
+    metrics.record_request();
+    metrics.record_request();
+    metrics.record_transport_error();
+    metrics.record_status(200);
+    metrics.record_status(404);
+    metrics.record_status(503);
+
+    assert_eq!(
+      metrics.snapshot(),
+      RequestCounters {
+        requests: 3,
+        transport_errors: 1,
+        client_errors: 1,
+        server_errors: 1,
+      }
+    );
+  }
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Result type for VictorOps API operations.
@@ -6,20 +8,48 @@ use thiserror::Error;
 pub type ApiResult<T> = std::result::Result<T, Error>;
 
 /// Error types that can occur when using the VictorOps API client.
+///
+/// New variants may be added in minor releases. Match on [`Error::kind`] for
+/// a stable, copyable classification.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
-  /// HTTP request failed.
+  /// HTTP request failed for a reason other than a timeout or connection failure.
   #[error("HTTP request failed: {0}")]
-  Http(#[from] reqwest::Error),
+  Http(#[source] reqwest::Error),
+
+  /// HTTP request timed out.
+  #[error("HTTP request timed out: {0}")]
+  Timeout(#[source] reqwest::Error),
+
+  /// Connecting to the server failed, for example because DNS resolution failed
+  /// or the connection was refused.
+  #[error("Connection failed: {0}")]
+  Connect(#[source] reqwest::Error),
 
   /// JSON serialization or deserialization failed.
   #[error("JSON serialization/deserialization failed: {0}")]
   Json(#[from] serde_json::Error),
 
+  /// A response body did not match the expected schema.
+  #[error("Failed to parse response at {path}: {message} (body: {snippet})")]
+  Deserialize {
+    /// The JSON path of the field that failed to parse, such as `teams[0].slug`.
+    path: String,
+    /// The underlying parse error.
+    message: String,
+    /// The start of the response body.
+    snippet: String,
+  },
+
   /// URL parsing failed.
   #[error("URL parsing failed: {0}")]
   UrlParse(#[from] url::ParseError),
 
+  /// Reading or writing a file failed.
+  #[error("I/O error: {0}")]
+  Io(#[from] std::io::Error),
+
   /// Invalid HTTP header value.
   #[error("Invalid header value: {0}")]
   InvalidHeaderValue(#[from] reqwest::header::InvalidHeaderValue),
@@ -31,19 +61,268 @@ pub enum Error {
     status: u16,
     /// The error message returned by the API.
     message: String,
+    /// Selected response headers by lowercase name, such as the request ID,
+    /// rate-limit counters, and content type.
+    headers: BTreeMap<String, String>,
   },
 
   /// Authentication failed.
   #[error("Authentication failed")]
   Authentication,
 
+  /// The API could not be reached.
+  #[error("VictorOps API unreachable: {0}")]
+  Unreachable(String),
+
   /// Requested resource was not found.
-  #[error("Resource not found")]
-  NotFound,
+  #[error("Resource not found: {endpoint}")]
+  NotFound {
+    /// The path of the endpoint that reported the resource missing.
+    endpoint: String,
+    /// The error message returned by the API.
+    message: String,
+  },
+
+  /// The API rejected a write that conflicts with the resource's current state,
+  /// such as an update carrying a stale `version`.
+  #[error("Conflict at {endpoint}: {message}")]
+  Conflict {
+    /// The path of the endpoint that reported the conflict.
+    endpoint: String,
+    /// The version the server expected, if the response said.
+    expected_version: Option<i32>,
+    /// The error message returned by the API.
+    message: String,
+  },
+
+  /// The request failed after being retried.
+  #[error("Request failed after {} attempts: {last}", attempts.len())]
+  RetriesExhausted {
+    /// Every attempt made, in order, ending with the one that gave up.
+    attempts: Vec<FailedAttempt>,
+    /// The error of the final attempt.
+    #[source]
+    last: Box<Error>,
+  },
 
   /// Invalid input provided to the API.
   #[error("Invalid input: {0}")]
   InvalidInput(String),
+
+  /// Credentials could not be obtained from the credentials provider.
+  #[error("Failed to obtain credentials: {0}")]
+  Credentials(String),
+
+  /// Request was cancelled before it completed.
+  #[error("Request cancelled")]
+  Cancelled,
+
+  /// Request did not complete before the client's deadline.
+  #[error("Request deadline exceeded")]
+  DeadlineExceeded,
+
+  /// Response body exceeded the configured maximum size.
+  #[error("Response body exceeded the maximum size of {limit} bytes")]
+  ResponseTooLarge {
+    /// The configured maximum response size in bytes.
+    limit: usize,
+  },
+
+  /// API rejected the request because the rate limit was exceeded.
+  #[error("Rate limited{}", retry_after.map(|d| format!(", retry after {}s", d.as_secs())).unwrap_or_default())]
+  RateLimited {
+    /// How long the API asked the client to wait before retrying, if it said.
+    retry_after: Option<Duration>,
+  },
+}
+
+impl From<reqwest::Error> for Error {
+  fn from(error: reqwest::Error) -> Self {
+    if error.is_timeout() {
+      Error::Timeout(error)
+    } else if error.is_connect() {
+      Error::Connect(error)
+    } else {
+      Error::Http(error)
+    }
+  }
+}
+
+/// One failed attempt of a request made by the client's retry layer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize-errors", derive(serde::Serialize))]
+pub struct FailedAttempt {
+  /// The HTTP status of the response, if one was received.
+  pub status: Option<u16>,
+  /// A description of the failure.
+  pub message: String,
+}
+
+impl From<&Error> for FailedAttempt {
+  fn from(error: &Error) -> Self {
+    FailedAttempt {
+      status: error.status(),
+      message: error.to_string(),
+    }
+  }
+}
+
+/// A stable classification of [`Error`], returned by [`Error::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize-errors", derive(serde::Serialize))]
+#[non_exhaustive]
+pub enum ErrorKind {
+  /// The HTTP request failed.
+  Http,
+  /// The HTTP request timed out.
+  Timeout,
+  /// Connecting to the server failed.
+  Connect,
+  /// JSON serialization or deserialization failed.
+  Json,
+  /// A response body did not match the expected schema.
+  Deserialize,
+  /// A URL could not be parsed.
+  UrlParse,
+  /// Reading or writing a file failed.
+  Io,
+  /// A header value was invalid.
+  InvalidHeaderValue,
+  /// The API returned an error response.
+  Api,
+  /// Authentication failed.
+  Authentication,
+  /// The API could not be reached.
+  Unreachable,
+  /// The requested resource was not found.
+  NotFound,
+  /// A write conflicted with the resource's current state.
+  Conflict,
+  /// The input was invalid.
+  InvalidInput,
+  /// Credentials could not be obtained.
+  Credentials,
+  /// The request was cancelled.
+  Cancelled,
+  /// The request deadline passed.
+  DeadlineExceeded,
+  /// The response body was too large.
+  ResponseTooLarge,
+  /// The request was rate limited.
+  RateLimited,
+  /// The request failed after being retried.
+  RetriesExhausted,
+}
+
+impl Error {
+  /// Returns the kind of error.
+  pub fn kind(&self) -> ErrorKind {
+    match self {
+      Error::Http(_) => ErrorKind::Http,
+      Error::Timeout(_) => ErrorKind::Timeout,
+      Error::Connect(_) => ErrorKind::Connect,
+      Error::Json(_) => ErrorKind::Json,
+      Error::Deserialize { .. } => ErrorKind::Deserialize,
+      Error::UrlParse(_) => ErrorKind::UrlParse,
+      Error::Io(_) => ErrorKind::Io,
+      Error::InvalidHeaderValue(_) => ErrorKind::InvalidHeaderValue,
+      Error::Api { .. } => ErrorKind::Api,
+      Error::Authentication => ErrorKind::Authentication,
+      Error::Unreachable(_) => ErrorKind::Unreachable,
+      Error::NotFound { .. } => ErrorKind::NotFound,
+      Error::Conflict { .. } => ErrorKind::Conflict,
+      Error::InvalidInput(_) => ErrorKind::InvalidInput,
+      Error::Credentials(_) => ErrorKind::Credentials,
+      Error::Cancelled => ErrorKind::Cancelled,
+      Error::DeadlineExceeded => ErrorKind::DeadlineExceeded,
+      Error::ResponseTooLarge { .. } => ErrorKind::ResponseTooLarge,
+      Error::RateLimited { .. } => ErrorKind::RateLimited,
+      Error::RetriesExhausted { .. } => ErrorKind::RetriesExhausted,
+    }
+  }
+
+  /// Returns the HTTP status code of the response that caused the error, if any.
+  pub fn status(&self) -> Option<u16> {
+    match self {
+      Error::Api { status, .. } => Some(*status),
+      Error::NotFound { .. } => Some(404),
+      Error::Conflict { .. } => Some(409),
+      Error::RateLimited { .. } => Some(429),
+      Error::Http(error) => error.status().map(|status| status.as_u16()),
+      Error::RetriesExhausted { last, .. } => last.status(),
+      _ => None,
+    }
+  }
+
+  /// Returns true if the API rejected the request with a 4xx status.
+  pub fn is_client_error(&self) -> bool {
+    self
+      .status()
+      .is_some_and(|status| (400..500).contains(&status))
+  }
+
+  /// Returns true if the same request may succeed when sent again.
+  ///
+  /// Rate limiting, server errors, timeouts, and connection failures are
+  /// retryable. Client errors, cancellation, and expired deadlines are not.
+  pub fn is_retryable(&self) -> bool {
+    match self {
+      Error::RateLimited { .. } | Error::Unreachable(_) | Error::Timeout(_) | Error::Connect(_) => {
+        true
+      }
+      Error::Api { status, .. } => *status >= 500,
+      Error::RetriesExhausted { last, .. } => last.is_retryable(),
+      _ => false,
+    }
+  }
+}
+
+/// Serializes an error as a flat report for logging and queue pipelines.
+///
+/// Every report has `kind`, `message`, and `retryable` fields, `status` when
+/// the error came from an HTTP response, and the structured fields of the
+/// variant, such as `endpoint`, `headers`, or `attempts`.
+#[cfg(feature = "serialize-errors")]
+impl serde::Serialize for Error {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    use serde::ser::SerializeMap;
+
+    let mut map = serializer.serialize_map(None)?;
+    map.serialize_entry("kind", &self.kind())?;
+    map.serialize_entry("message", &self.to_string())?;
+    map.serialize_entry("retryable", &self.is_retryable())?;
+    if let Some(status) = self.status() {
+      map.serialize_entry("status", &status)?;
+    }
+
+    match self {
+      Error::Api { headers, .. } => map.serialize_entry("headers", headers)?,
+      Error::NotFound { endpoint, .. } => map.serialize_entry("endpoint", endpoint)?,
+      Error::Conflict {
+        endpoint,
+        expected_version,
+        ..
+      } => {
+        map.serialize_entry("endpoint", endpoint)?;
+        map.serialize_entry("expected_version", expected_version)?;
+      }
+      Error::Deserialize { path, snippet, .. } => {
+        map.serialize_entry("path", path)?;
+        map.serialize_entry("snippet", snippet)?;
+      }
+      Error::RateLimited { retry_after } => {
+        map.serialize_entry("retry_after_secs", &retry_after.map(|d| d.as_secs_f64()))?;
+      }
+      Error::RetriesExhausted { attempts, last } => {
+        map.serialize_entry("attempts", attempts)?;
+        map.serialize_entry("last", last)?;
+      }
+      Error::ResponseTooLarge { limit } => map.serialize_entry("limit", limit)?,
+      _ => {}
+    }
+
+    map.end()
+  }
 }
 
 #[cfg(test)]
@@ -55,20 +334,171 @@ mod tests {
     let api_error = Error::Api {
       status: 404,
       message: "Not found".to_string(),
+      headers: BTreeMap::new(),
     };
     assert_eq!(format!("{}", api_error), "API error: 404 - Not found");
 
     let auth_error = Error::Authentication;
     assert_eq!(format!("{}", auth_error), "Authentication failed");
 
-    let not_found_error = Error::NotFound;
-    assert_eq!(format!("{}", not_found_error), "Resource not found");
+    let not_found_error = Error::NotFound {
+      endpoint: "/api-public/v1/user/jdoe".to_string(),
+      message: "User not found".to_string(),
+    };
+    assert_eq!(
+      format!("{}", not_found_error),
+      "Resource not found: /api-public/v1/user/jdoe"
+    );
+
+    let unreachable_error = Error::Unreachable("connection refused".to_string());
+    assert_eq!(
+      format!("{}", unreachable_error),
+      "VictorOps API unreachable: connection refused"
+    );
 
     let invalid_input_error = Error::InvalidInput("Bad data".to_string());
     assert_eq!(
       format!("{}", invalid_input_error),
       "Invalid input: Bad data"
     );
+
+    let io_error = Error::from(std::io::Error::new(
+      std::io::ErrorKind::NotFound,
+      "cassette missing",
+    ));
+    assert_eq!(format!("{}", io_error), "I/O error: cassette missing");
+
+    let deserialize_error = Error::Deserialize {
+      path: "teams[0].slug".to_string(),
+      message: "invalid type: integer `7`, expected a string".to_string(),
+      snippet: r#"{"teams": [{"slug": 7}]}"#.to_string(),
+    };
+    assert_eq!(
+      format!("{}", deserialize_error),
+      r#"Failed to parse response at teams[0].slug: invalid type: integer `7`, expected a string (body: {"teams": [{"slug": 7}]})"#
+    );
+
+    let cancelled_error = Error::Cancelled;
+    assert_eq!(format!("{}", cancelled_error), "Request cancelled");
+
+    let deadline_error = Error::DeadlineExceeded;
+    assert_eq!(format!("{}", deadline_error), "Request deadline exceeded");
+
+    let too_large_error = Error::ResponseTooLarge { limit: 1024 };
+    assert_eq!(
+      format!("{}", too_large_error),
+      "Response body exceeded the maximum size of 1024 bytes"
+    );
+
+    let rate_limited_error = Error::RateLimited {
+      retry_after: Some(Duration::from_secs(30)),
+    };
+    assert_eq!(
+      format!("{}", rate_limited_error),
+      "Rate limited, retry after 30s"
+    );
+
+    let rate_limited_error = Error::RateLimited { retry_after: None };
+    assert_eq!(format!("{}", rate_limited_error), "Rate limited");
+  }
+
+  #[test]
+  fn test_error_classification() {
+    let server_error = Error::Api {
+      status: 503,
+      message: "Service unavailable".to_string(),
+      headers: BTreeMap::new(),
+    };
+    assert_eq!(server_error.status(), Some(503));
+    assert!(server_error.is_retryable());
+    assert!(!server_error.is_client_error());
+
+    let bad_request = Error::Api {
+      status: 400,
+      message: "Bad request".to_string(),
+      headers: BTreeMap::new(),
+    };
+    assert!(bad_request.is_client_error());
+    assert!(!bad_request.is_retryable());
+
+    let not_found = Error::NotFound {
+      endpoint: "/api-public/v1/team/missing".to_string(),
+      message: String::new(),
+    };
+    assert_eq!(not_found.status(), Some(404));
+    assert!(not_found.is_client_error());
+    assert!(!not_found.is_retryable());
+
+    let rate_limited = Error::RateLimited { retry_after: None };
+    assert_eq!(rate_limited.status(), Some(429));
+    assert!(rate_limited.is_client_error());
+    assert!(rate_limited.is_retryable());
+
+    assert!(Error::Unreachable("connection refused".to_string()).is_retryable());
+    assert!(!Error::Cancelled.is_retryable());
+    assert!(!Error::DeadlineExceeded.is_retryable());
+    assert_eq!(Error::Authentication.status(), None);
+    assert!(!Error::InvalidInput("Bad data".to_string()).is_client_error());
+  }
+
+  #[test]
+  fn test_error_kind() {
+    assert_eq!(Error::Cancelled.kind(), ErrorKind::Cancelled);
+    assert_eq!(
+      Error::RateLimited { retry_after: None }.kind(),
+      ErrorKind::RateLimited
+    );
+    assert_eq!(
+      Error::NotFound {
+        endpoint: "/api-public/v1/team/missing".to_string(),
+        message: String::new(),
+      }
+      .kind(),
+      ErrorKind::NotFound
+    );
+    assert_eq!(
+      Error::from(serde_json::from_str::<u8>("x").unwrap_err()).kind(),
+      ErrorKind::Json
+    );
+  }
+
+  #[cfg(feature = "serialize-errors")]
+  #[test]
+  fn test_error_serialize() {
+    let rate_limited = Error::RateLimited {
+      retry_after: Some(Duration::from_secs(30)),
+    };
+    let error = Error::RetriesExhausted {
+      attempts: vec![FailedAttempt::from(&rate_limited)],
+      last: Box::new(rate_limited),
+    };
+
+    assert_eq!(
+      serde_json::to_value(&error).unwrap(),
+      serde_json::json!({
+        "kind": "RetriesExhausted",
+        "message": "Request failed after 1 attempts: Rate limited, retry after 30s",
+        "retryable": true,
+        "status": 429,
+        "attempts": [{"status": 429, "message": "Rate limited, retry after 30s"}],
+        "last": {
+          "kind": "RateLimited",
+          "message": "Rate limited, retry after 30s",
+          "retryable": true,
+          "status": 429,
+          "retry_after_secs": 30.0
+        }
+      })
+    );
+
+    let not_found = Error::NotFound {
+      endpoint: "/api-public/v1/user/jdoe".to_string(),
+      message: "User not found".to_string(),
+    };
+    assert_eq!(
+      serde_json::to_value(&not_found).unwrap()["endpoint"],
+      "/api-public/v1/user/jdoe"
+    );
   }
 
   #[test]
@@ -76,6 +506,7 @@ mod tests {
     let api_error = Error::Api {
       status: 500,
       message: "Internal error".to_string(),
+      headers: BTreeMap::new(),
     };
     let debug_str = format!("{:?}", api_error);
     assert!(debug_str.contains("Api"));
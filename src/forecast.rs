@@ -0,0 +1,276 @@
+use crate::types::*;
+use chrono::{DateTime, TimeDelta, Utc};
+use std::collections::HashMap;
+
+/// A span of on-call time, from start to end.
+type Interval = (DateTime<Utc>, DateTime<Utc>);
+
+/// The forecasted on-call load for a single user.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserLoad {
+  /// The username of the on-call user.
+  pub username: String,
+  /// The number of hours the user is on-call within the forecast window.
+  ///
+  /// Overlapping assignments are counted once, so this never exceeds the
+  /// length of the window.
+  pub hours: f64,
+  /// The number of hours in which the user holds two or more assignments at
+  /// once, such as rolls on two teams' rotas.
+  pub double_booked_hours: f64,
+  /// Whether the user's on-call hours exceed the forecast threshold.
+  pub over_threshold: bool,
+}
+
+/// Hours-on-call per user across one or more team schedules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadForecast {
+  /// The start of the forecast window.
+  pub window_start: DateTime<Utc>,
+  /// The end of the forecast window.
+  pub window_end: DateTime<Utc>,
+  /// The number of on-call hours above which a user is flagged.
+  pub threshold_hours: f64,
+  /// The per-user load, sorted by descending hours.
+  pub users: Vec<UserLoad>,
+}
+
+impl LoadForecast {
+  /// Computes the on-call load from a set of team schedules.
+  ///
+  /// Rolls are attributed to their on-call user, with any overlapping override
+  /// reassigning that portion of the roll to the overriding user. Hours are
+  /// clipped to the window and merged across every team and policy, so a user
+  /// who is double-booked on two rotas is on-call once for the overlap; the
+  /// overlap is reported separately as [`UserLoad::double_booked_hours`].
+  ///
+  /// # Arguments
+  ///
+  /// * `schedules` - The team schedules to analyze
+  /// * `window_start` - The start of the forecast window
+  /// * `days` - The length of the forecast window in days; windows that
+  ///   would end past the latest representable time end there instead
+  /// * `threshold_hours` - The number of hours above which a user is flagged
+  pub fn from_schedules(
+    schedules: &[ApiTeamSchedule],
+    window_start: DateTime<Utc>,
+    days: i64,
+    threshold_hours: f64,
+  ) -> Self {
+    let window_end = TimeDelta::try_days(days)
+      .and_then(|length| window_start.checked_add_signed(length))
+      .unwrap_or(DateTime::<Utc>::MAX_UTC);
+    let mut intervals: HashMap<String, Vec<Interval>> = HashMap::new();
+
+    for team_schedule in schedules {
+      for policy_schedule in &team_schedule.schedules {
+        for entry in policy_schedule.resolve() {
+          let start = entry.start.max(window_start);
+          let end = entry.end.min(window_end);
+
+          if end > start {
+            intervals.entry(entry.user).or_default().push((start, end));
+          }
+        }
+      }
+    }
+
+    let mut users: Vec<UserLoad> = intervals
+      .into_iter()
+      .map(|(username, intervals)| {
+        let (on_call, double_booked) = coverage(&intervals);
+        let hours = on_call as f64 / 3600.0;
+        UserLoad {
+          username,
+          hours,
+          double_booked_hours: double_booked as f64 / 3600.0,
+          over_threshold: hours > threshold_hours,
+        }
+      })
+      .collect();
+
+    users.sort_by(|a, b| {
+      b.hours
+        .total_cmp(&a.hours)
+        .then_with(|| a.username.cmp(&b.username))
+    });
+
+    LoadForecast {
+      window_start,
+      window_end,
+      threshold_hours,
+      users,
+    }
+  }
+
+  /// Returns the users whose on-call hours exceed the threshold.
+  pub fn overloaded(&self) -> impl Iterator<Item = &UserLoad> {
+    self.users.iter().filter(|user| user.over_threshold)
+  }
+
+  /// Returns the load for a specific user, if they are on-call in the window.
+  pub fn user(&self, username: &str) -> Option<&UserLoad> {
+    self.users.iter().find(|user| user.username == username)
+  }
+
+  /// Returns the users who hold overlapping assignments in the window.
+  pub fn double_booked(&self) -> impl Iterator<Item = &UserLoad> {
+    self
+      .users
+      .iter()
+      .filter(|user| user.double_booked_hours > 0.0)
+  }
+}
+
+/// Returns the seconds covered by at least one interval and by two or more.
+fn coverage(intervals: &[Interval]) -> (i64, i64) {
+  let mut edges: Vec<(DateTime<Utc>, i32)> = intervals
+    .iter()
+    .flat_map(|&(start, end)| [(start, 1), (end, -1)])
+    .collect();
+  edges.sort();
+
+  let (mut covered, mut overlapped, mut depth) = (0, 0, 0);
+  for pair in edges.windows(2) {
+    let ((at, change), (next, _)) = (pair[0], pair[1]);
+    depth += change;
+    let seconds = (next - at).num_seconds();
+    if depth >= 1 {
+      covered += seconds;
+    }
+    if depth >= 2 {
+      overlapped += seconds;
+    }
+  }
+
+  (covered, overlapped)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::TimeZone;
+
+  fn at(day: u32, hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, day, hour, 0, 0).unwrap()
+  }
+
+  fn roll(username: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> ApiOnCallRoll {
+    ApiOnCallRoll {
+      start: Some(start),
+      end: Some(end),
+      on_call_user: Some(ApiUser {
+        username: Some(username.to_string()),
+      }),
+      is_roll: Some(true),
+    }
+  }
+
+  fn team_schedule(
+    rolls: Vec<ApiOnCallRoll>,
+    overrides: Vec<ApiOnCallOverride>,
+  ) -> ApiTeamSchedule {
+    ApiTeamSchedule {
+      team: None,
+      schedules: vec![ApiEscalationPolicySchedule {
+        policy: None,
+        schedule: vec![ApiOnCallEntry {
+          on_call_user: None,
+          override_on_call_user: None,
+          on_call_type: None,
+          rotation_name: None,
+          shift_name: None,
+          shift_roll: None,
+          rolls,
+        }],
+        overrides,
+      }],
+    }
+  }
+
+  #[test]
+  fn test_forecast_sums_hours_across_teams() {
+    let schedules = vec![
+      team_schedule(vec![roll("alice", at(1, 0), at(2, 0))], vec![]),
+      team_schedule(
+        vec![
+          roll("alice", at(1, 12), at(2, 12)),
+          roll("bob", at(2, 12), at(3, 0)),
+        ],
+        vec![],
+      ),
+    ];
+
+    let forecast = LoadForecast::from_schedules(&schedules, at(1, 0), 7, 40.0);
+
+    assert_eq!(forecast.window_end, at(8, 0));
+    assert_eq!(forecast.users[0].username, "alice");
+    assert_eq!(forecast.users[0].hours, 36.0);
+    assert_eq!(forecast.users[0].double_booked_hours, 12.0);
+    assert!(!forecast.users[0].over_threshold);
+    assert_eq!(forecast.user("bob").unwrap().hours, 12.0);
+    assert_eq!(forecast.user("bob").unwrap().double_booked_hours, 0.0);
+
+    let double_booked: Vec<_> = forecast.double_booked().map(|u| &u.username).collect();
+    assert_eq!(double_booked, vec!["alice"]);
+
+    let forecast = LoadForecast::from_schedules(&schedules, at(1, 0), 7, 30.0);
+    let overloaded: Vec<_> = forecast.overloaded().map(|u| &u.username).collect();
+    assert_eq!(overloaded, vec!["alice"]);
+  }
+
+  #[test]
+  fn test_forecast_saturates_long_windows() {
+    let schedules = vec![team_schedule(
+      vec![roll("alice", at(1, 0), at(2, 0))],
+      vec![],
+    )];
+
+    let forecast = LoadForecast::from_schedules(&schedules, at(1, 0), i64::MAX, 100.0);
+
+    assert_eq!(forecast.window_end, DateTime::<Utc>::MAX_UTC);
+    assert_eq!(forecast.users[0].hours, 24.0);
+  }
+
+  #[test]
+  fn test_forecast_clips_to_window() {
+    let schedules = vec![team_schedule(
+      vec![
+        roll("alice", at(1, 0), at(3, 0)),
+        roll("bob", at(5, 0), at(6, 0)),
+      ],
+      vec![],
+    )];
+
+    let forecast = LoadForecast::from_schedules(&schedules, at(2, 0), 2, 100.0);
+
+    assert_eq!(forecast.users.len(), 1);
+    assert_eq!(forecast.users[0].username, "alice");
+    assert_eq!(forecast.users[0].hours, 24.0);
+    assert_eq!(forecast.overloaded().count(), 0);
+    assert_eq!(forecast.double_booked().count(), 0);
+  }
+
+  #[test]
+  fn test_forecast_applies_overrides() {
+    let schedules = vec![team_schedule(
+      vec![roll("alice", at(1, 0), at(2, 0))],
+      vec![ApiOnCallOverride {
+        orig_on_call_user: Some(ApiUser {
+          username: Some("alice".to_string()),
+        }),
+        override_on_call_user: Some(ApiUser {
+          username: Some("bob".to_string()),
+        }),
+        start: Some(at(1, 6)),
+        end: Some(at(1, 12)),
+        policy: None,
+      }],
+    )];
+
+    let forecast = LoadForecast::from_schedules(&schedules, at(1, 0), 1, 24.0);
+
+    assert_eq!(forecast.user("alice").unwrap().hours, 18.0);
+    assert_eq!(forecast.user("bob").unwrap().hours, 6.0);
+  }
+}
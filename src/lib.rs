@@ -1,20 +1,103 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+/// Client for the VictorOps REST alert ingestion endpoint.
+pub mod alerts;
+
+/// Opt-in response caching for read-heavy endpoints.
+pub mod cache;
+
 /// VictorOps API client implementation.
 pub mod client;
 
+/// Credential providers for authenticating API requests.
+pub mod credentials;
+
+/// Client diagnostics for support bundles.
+pub mod diagnostics;
+
 /// Error types and result handling for the VictorOps API.
 pub mod error;
 
+/// On-call load forecasting across team schedules.
+#[cfg(feature = "chrono")]
+pub mod forecast;
+
+/// Export of organization configuration for migration to other providers.
+pub mod migrate;
+
+/// Domain types built from the raw API shapes.
+pub mod model;
+
+/// MessagePack encoding of the crate's serde types.
+#[cfg(feature = "rmp-serde")]
+pub mod msgpack;
+
+/// Paging through offset/limit endpoints.
+pub mod pagination;
+
+/// Declarative provisioning of teams, escalation policies, and routing keys.
+pub mod provision;
+
+/// Borrowed views of large responses for allocation-free parsing.
+pub mod raw;
+
+/// Normalized on-call intervals resolved from schedules.
+#[cfg(feature = "chrono")]
+pub mod schedule;
+
+/// Pluggable HTTP transports.
+pub mod transport;
+
 /// Type definitions for VictorOps API requests and responses.
 pub mod types;
 
+/// Client-side validation of request types.
+pub mod validate;
+
+/// Parsing of VictorOps outgoing webhooks.
+pub mod webhook;
+
 /// Main HTTP client for interacting with the VictorOps API.
-pub use client::Client;
+pub use client::{BulkResult, Client, ClientBuilder, RateLimitPolicy};
+
+/// Client for sending alerts to the REST integration endpoint.
+pub use alerts::{AlertClient, AlertMessage, AlertResponse, EntityId, MessageType};
+
+/// Response cache configuration.
+pub use cache::{CacheClass, CacheConfig};
+
+/// Token used to cancel in-flight requests.
+pub use tokio_util::sync::CancellationToken;
+
+/// Credential provider trait and built-in providers.
+pub use credentials::{Credentials, CredentialsProvider, RefreshingCredentials, StaticCredentials};
+
+/// Client diagnostics snapshot types.
+pub use diagnostics::{DebugInfo, RequestCounters};
 
 /// Result type and error types for VictorOps API operations.
-pub use error::{ApiResult, Error};
+pub use error::{ApiResult, Error, ErrorKind, FailedAttempt};
+
+/// On-call load forecast types.
+#[cfg(feature = "chrono")]
+pub use forecast::{LoadForecast, UserLoad};
+
+/// Pagination types.
+pub use pagination::{Page, PageStream};
+
+/// Resolved on-call interval types.
+#[cfg(feature = "chrono")]
+pub use schedule::{ResolvedScheduleEntry, ScheduleSource};
+
+/// Transport trait for sending requests.
+pub use transport::{Transport, TransportFuture};
 
 /// All type definitions for VictorOps API data structures.
 pub use types::*;
+
+/// Validation trait for request types.
+pub use validate::Validate;
+
+/// Webhook event types.
+pub use webhook::{WebhookEvent, WebhookIncident};
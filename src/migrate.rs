@@ -0,0 +1,458 @@
+use crate::error::ApiResult;
+use crate::types::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The version of the neutral export format produced by [`MigrationExport`].
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// A point-in-time snapshot of an organization's paging configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct OrgSnapshot {
+  /// All teams in the organization.
+  pub teams: Vec<Team>,
+  /// Team members keyed by team slug.
+  pub members: BTreeMap<String, Vec<TeamMember>>,
+  /// Upcoming on-call schedules for each team.
+  pub schedules: Vec<ApiTeamSchedule>,
+  /// All escalation policies, including their steps.
+  pub escalation_policies: Vec<EscalationPolicy>,
+  /// All routing keys.
+  pub routing_keys: Vec<RoutingKeyResponse>,
+}
+
+/// A provider-neutral export of an organization's paging configuration.
+///
+/// The structure only uses plain strings, timestamps, and lists so it can be
+/// serialized to JSON, YAML, or any other serde format for import elsewhere.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MigrationExport {
+  /// The version of the export format.
+  pub version: u32,
+  /// The exported teams.
+  pub teams: Vec<ExportTeam>,
+  /// The exported escalation policies.
+  pub escalation_policies: Vec<ExportPolicy>,
+  /// The exported routing keys.
+  pub routing_keys: Vec<ExportRoutingKey>,
+}
+
+/// An exported team with its members and schedules.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExportTeam {
+  /// The name of the team.
+  pub name: Option<String>,
+  /// The slug identifier of the team.
+  pub slug: Option<String>,
+  /// The usernames of the team's members.
+  pub members: Vec<String>,
+  /// The team's schedules, one per escalation policy.
+  pub schedules: Vec<ExportSchedule>,
+}
+
+/// An exported on-call schedule for a single escalation policy.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExportSchedule {
+  /// The slug of the escalation policy the schedule belongs to.
+  pub policy: Option<String>,
+  /// The scheduled on-call shifts.
+  pub shifts: Vec<ExportShift>,
+  /// The on-call overrides.
+  pub overrides: Vec<ExportOverride>,
+}
+
+/// An exported on-call shift.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExportShift {
+  /// The username of the on-call user.
+  pub user: String,
+  /// The start of the shift.
+  pub start: Timestamp,
+  /// The end of the shift.
+  pub end: Timestamp,
+  /// The name of the rotation the shift belongs to.
+  pub rotation: Option<String>,
+  /// The name of the shift within the rotation.
+  pub shift: Option<String>,
+}
+
+/// An exported on-call override.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExportOverride {
+  /// The username of the originally scheduled user.
+  pub original_user: Option<String>,
+  /// The username of the user covering the override.
+  pub override_user: Option<String>,
+  /// The start of the override.
+  pub start: Option<Timestamp>,
+  /// The end of the override.
+  pub end: Option<Timestamp>,
+}
+
+/// An exported escalation policy.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExportPolicy {
+  /// The name of the escalation policy.
+  pub name: String,
+  /// The slug identifier of the escalation policy.
+  pub slug: String,
+  /// The slug of the team that owns the escalation policy.
+  pub team: String,
+  /// Whether custom paging policies are ignored.
+  pub ignore_custom_paging_policies: bool,
+  /// The escalation steps, in order.
+  pub steps: Vec<ExportStep>,
+}
+
+/// An exported escalation step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExportStep {
+  /// The timeout before escalating to the next step.
+  pub timeout: i32,
+  /// The targets notified by this step.
+  pub targets: Vec<ExportTarget>,
+}
+
+/// An exported escalation step target.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExportTarget {
+  /// Page a specific user.
+  User {
+    /// The username to page.
+    username: String,
+  },
+  /// Page whoever is on-call for a rotation group.
+  RotationGroup {
+    /// The slug of the rotation group.
+    slug: String,
+  },
+  /// Call a webhook.
+  Webhook {
+    /// The slug of the webhook.
+    slug: String,
+  },
+  /// Send an email.
+  Email {
+    /// The email address.
+    address: String,
+  },
+  /// Escalate to another escalation policy.
+  Policy {
+    /// The slug of the target escalation policy.
+    slug: String,
+  },
+  /// A target type this export does not understand.
+  Unknown {
+    /// The execution type reported by the API.
+    execution_type: Option<String>,
+  },
+}
+
+/// An exported routing key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExportRoutingKey {
+  /// The routing key value.
+  pub key: String,
+  /// The slugs of the escalation policies the key routes to.
+  pub policies: Vec<String>,
+}
+
+impl MigrationExport {
+  /// Serializes the export as pretty-printed JSON.
+  pub fn to_json(&self) -> ApiResult<String> {
+    Ok(serde_json::to_string_pretty(self)?)
+  }
+}
+
+impl From<&OrgSnapshot> for MigrationExport {
+  fn from(snapshot: &OrgSnapshot) -> Self {
+    let teams = snapshot
+      .teams
+      .iter()
+      .map(|team| {
+        let members = team
+          .slug
+          .as_ref()
+          .and_then(|slug| snapshot.members.get(slug))
+          .map(|users| users.iter().filter_map(|u| u.username.clone()).collect())
+          .unwrap_or_default();
+
+        let schedules = snapshot
+          .schedules
+          .iter()
+          .filter(|s| s.team.as_ref().and_then(|t| t.slug.as_ref()) == team.slug.as_ref())
+          .flat_map(|s| s.schedules.iter().map(export_schedule))
+          .collect();
+
+        ExportTeam {
+          name: team.name.clone(),
+          slug: team.slug.clone(),
+          members,
+          schedules,
+        }
+      })
+      .collect();
+
+    let escalation_policies = snapshot
+      .escalation_policies
+      .iter()
+      .map(|policy| ExportPolicy {
+        name: policy.name.clone(),
+        slug: policy.id.clone(),
+        team: policy.team_id.clone(),
+        ignore_custom_paging_policies: policy.ignore_custom_paging_policies,
+        steps: policy
+          .steps
+          .iter()
+          .map(|step| ExportStep {
+            timeout: step.timeout,
+            targets: step.entries.iter().map(export_target).collect(),
+          })
+          .collect(),
+      })
+      .collect();
+
+    let routing_keys = snapshot
+      .routing_keys
+      .iter()
+      .filter_map(|key| {
+        Some(ExportRoutingKey {
+          key: key.routing_key.clone()?,
+          policies: key
+            .targets
+            .iter()
+            .filter_map(|t| t.policy_slug().map(|slug| slug.to_string()))
+            .collect(),
+        })
+      })
+      .collect();
+
+    MigrationExport {
+      version: EXPORT_FORMAT_VERSION,
+      teams,
+      escalation_policies,
+      routing_keys,
+    }
+  }
+}
+
+fn export_schedule(schedule: &ApiEscalationPolicySchedule) -> ExportSchedule {
+  let shifts = schedule
+    .schedule
+    .iter()
+    .flat_map(|entry| {
+      entry.rolls.iter().filter_map(move |roll| {
+        Some(ExportShift {
+          user: roll.on_call_user.as_ref()?.username.clone()?,
+          start: roll.start.to_owned()?,
+          end: roll.end.to_owned()?,
+          rotation: entry.rotation_name.clone(),
+          shift: entry.shift_name.clone(),
+        })
+      })
+    })
+    .collect();
+
+  let overrides = schedule
+    .overrides
+    .iter()
+    .map(|o| ExportOverride {
+      original_user: o
+        .orig_on_call_user
+        .as_ref()
+        .and_then(|u| u.username.clone()),
+      override_user: o
+        .override_on_call_user
+        .as_ref()
+        .and_then(|u| u.username.clone()),
+      start: o.start.to_owned(),
+      end: o.end.to_owned(),
+    })
+    .collect();
+
+  ExportSchedule {
+    policy: schedule.policy.as_ref().and_then(|p| p.slug.clone()),
+    shifts,
+    overrides,
+  }
+}
+
+fn export_target(entry: &EscalationPolicyStepEntry) -> ExportTarget {
+  let field = |map: &Option<BTreeMap<String, String>>, key: &str| {
+    map.as_ref().and_then(|m| m.get(key).cloned())
+  };
+
+  if let Some(username) = field(&entry.user, "username") {
+    ExportTarget::User { username }
+  } else if let Some(slug) = field(&entry.rotation_group, "slug") {
+    ExportTarget::RotationGroup { slug }
+  } else if let Some(slug) = field(&entry.webhook, "slug") {
+    ExportTarget::Webhook { slug }
+  } else if let Some(address) = field(&entry.email, "address") {
+    ExportTarget::Email { address }
+  } else if let Some(slug) = field(&entry.target_policy, "policySlug") {
+    ExportTarget::Policy { slug }
+  } else {
+    ExportTarget::Unknown {
+      execution_type: entry.execution_type.clone(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn snapshot() -> OrgSnapshot {
+    let schedule: ApiTeamSchedule = serde_json::from_str(
+      r#"{
+        "team": {"name": "Engineering", "slug": "engineering"},
+        "schedules": [
+          {
+            "policy": {"name": "Primary", "slug": "pol-primary"},
+            "schedule": [
+              {
+                "rotationName": "Weekly",
+                "shiftName": "Day",
+                "rolls": [
+                  {
+                    "start": "2026-01-01T00:00:00Z",
+                    "end": "2026-01-08T00:00:00Z",
+                    "onCallUser": {"username": "alice"}
+                  }
+                ]
+              }
+            ],
+            "overrides": [
+              {
+                "origOnCallUser": {"username": "alice"},
+                "overrideOnCallUser": {"username": "bob"},
+                "start": "2026-01-02T00:00:00Z",
+                "end": "2026-01-03T00:00:00Z"
+              }
+            ]
+          }
+        ]
+      }"#,
+    )
+    .unwrap();
+
+    let mut members = BTreeMap::new();
+    members.insert(
+      "engineering".to_string(),
+      vec![
+        serde_json::from_str(r#"{"username": "alice"}"#).unwrap(),
+        serde_json::from_str(r#"{"username": "bob"}"#).unwrap(),
+      ],
+    );
+
+    OrgSnapshot {
+      teams: vec![
+        serde_json::from_str(r#"{"name": "Engineering", "slug": "engineering"}"#).unwrap(),
+      ],
+      members,
+      schedules: vec![schedule],
+      escalation_policies: vec![EscalationPolicy {
+        name: "Primary".to_string(),
+        team_id: "engineering".to_string(),
+        ignore_custom_paging_policies: false,
+        steps: vec![EscalationPolicySteps {
+          timeout: 0,
+          entries: vec![
+            EscalationPolicyStepEntry {
+              execution_type: Some("rotation_group".to_string()),
+              user: None,
+              rotation_group: Some(BTreeMap::from([(
+                "slug".to_string(),
+                "rtg-weekly".to_string(),
+              )])),
+              webhook: None,
+              email: None,
+              target_policy: None,
+            },
+            EscalationPolicyStepEntry {
+              execution_type: Some("carrier_pigeon".to_string()),
+              user: None,
+              rotation_group: None,
+              webhook: None,
+              email: None,
+              target_policy: None,
+            },
+          ],
+        }],
+        id: "pol-primary".to_string(),
+      }],
+      routing_keys: vec![RoutingKeyResponse {
+        routing_key: Some("database".to_string()),
+        targets: vec![RoutingTarget::policy("pol-primary")],
+      }],
+    }
+  }
+
+  #[test]
+  fn test_export_from_snapshot() {
+    let export = MigrationExport::from(&snapshot());
+
+    assert_eq!(export.version, EXPORT_FORMAT_VERSION);
+    assert_eq!(export.teams.len(), 1);
+
+    let team = &export.teams[0];
+    assert_eq!(team.slug.as_deref(), Some("engineering"));
+    assert_eq!(team.members, vec!["alice", "bob"]);
+    assert_eq!(team.schedules.len(), 1);
+    assert_eq!(team.schedules[0].policy.as_deref(), Some("pol-primary"));
+    assert_eq!(team.schedules[0].shifts[0].user, "alice");
+    assert_eq!(
+      team.schedules[0].shifts[0].rotation.as_deref(),
+      Some("Weekly")
+    );
+    assert_eq!(
+      team.schedules[0].overrides[0].override_user.as_deref(),
+      Some("bob")
+    );
+
+    let policy = &export.escalation_policies[0];
+    assert_eq!(policy.slug, "pol-primary");
+    assert_eq!(
+      policy.steps[0].targets,
+      vec![
+        ExportTarget::RotationGroup {
+          slug: "rtg-weekly".to_string()
+        },
+        ExportTarget::Unknown {
+          execution_type: Some("carrier_pigeon".to_string())
+        },
+      ]
+    );
+
+    assert_eq!(
+      export.routing_keys,
+      vec![ExportRoutingKey {
+        key: "database".to_string(),
+        policies: vec!["pol-primary".to_string()],
+      }]
+    );
+  }
+
+  #[test]
+  fn test_export_json_round_trip() {
+    let export = MigrationExport::from(&snapshot());
+    let json = export.to_json().unwrap();
+
+    assert!(json.contains(r#""type": "rotation_group""#));
+
+    let parsed: MigrationExport = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, export);
+  }
+}
@@ -0,0 +1,283 @@
+use crate::types::{
+  self, ApiEscalationPolicy, ApiEscalationPolicySchedule, ApiTeam, ApiTeamSchedule, Incident,
+  PagedEntity, PagedPolicy, Severity, Timestamp,
+};
+
+/// A team, identified by its slug.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Team {
+  /// The display name of the team.
+  pub name: String,
+  /// The unique slug of the team.
+  pub slug: String,
+}
+
+impl Team {
+  fn from_parts(name: &Option<String>, slug: &Option<String>) -> Self {
+    Team {
+      name: name.clone().unwrap_or_default(),
+      slug: slug.clone().unwrap_or_default(),
+    }
+  }
+}
+
+impl From<&types::Team> for Team {
+  fn from(team: &types::Team) -> Self {
+    Team::from_parts(&team.name, &team.slug)
+  }
+}
+
+impl From<&ApiTeam> for Team {
+  fn from(team: &ApiTeam) -> Self {
+    Team::from_parts(&team.name, &team.slug)
+  }
+}
+
+impl From<&PagedEntity> for Team {
+  fn from(entity: &PagedEntity) -> Self {
+    Team::from_parts(&entity.name, &entity.slug)
+  }
+}
+
+/// An escalation policy and, when known, the team that owns it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Policy {
+  /// The display name of the policy.
+  pub name: String,
+  /// The unique slug of the policy.
+  pub slug: String,
+  /// The team that owns the policy.
+  pub team: Option<Team>,
+}
+
+impl From<&ApiEscalationPolicy> for Policy {
+  fn from(policy: &ApiEscalationPolicy) -> Self {
+    Policy {
+      name: policy.name.clone().unwrap_or_default(),
+      slug: policy.slug.clone().unwrap_or_default(),
+      team: None,
+    }
+  }
+}
+
+impl From<&PagedPolicy> for Policy {
+  fn from(paged: &PagedPolicy) -> Self {
+    let entity = paged.policy.as_ref();
+    Policy {
+      name: entity.and_then(|p| p.name.clone()).unwrap_or_default(),
+      slug: entity.and_then(|p| p.slug.clone()).unwrap_or_default(),
+      team: paged.team.as_ref().map(Team::from),
+    }
+  }
+}
+
+/// A span of time during which a user is scheduled on-call.
+///
+/// Periods come straight from the schedule's rolls; overrides are not
+/// applied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct OnCallPeriod {
+  /// The username of the on-call user.
+  pub user: String,
+  /// When the period starts.
+  pub start: Timestamp,
+  /// When the period ends.
+  pub end: Timestamp,
+  /// The policy the period belongs to.
+  pub policy: Option<Policy>,
+  /// The rotation the period comes from.
+  pub rotation: Option<String>,
+  /// The shift within the rotation.
+  pub shift: Option<String>,
+}
+
+impl ApiEscalationPolicySchedule {
+  /// Returns the scheduled on-call periods for this policy.
+  ///
+  /// Rolls missing a user or either bound are skipped.
+  ///
+  /// # Returns
+  ///
+  /// The periods in schedule order.
+  pub fn periods(&self) -> Vec<OnCallPeriod> {
+    let policy = self.policy.as_ref().map(Policy::from);
+
+    self
+      .schedule
+      .iter()
+      .flat_map(|entry| entry.rolls.iter().map(move |roll| (entry, roll)))
+      .filter_map(|(entry, roll)| {
+        Some(OnCallPeriod {
+          user: roll.on_call_user.as_ref()?.username.clone()?,
+          start: roll.start.to_owned()?,
+          end: roll.end.to_owned()?,
+          policy: policy.clone(),
+          rotation: entry.rotation_name.clone(),
+          shift: entry.shift_name.clone(),
+        })
+      })
+      .collect()
+  }
+}
+
+impl ApiTeamSchedule {
+  /// Returns the scheduled on-call periods across the team's policies.
+  ///
+  /// Each period's policy carries the team.
+  pub fn periods(&self) -> Vec<OnCallPeriod> {
+    let team = self.team.as_ref().map(Team::from);
+
+    self
+      .schedules
+      .iter()
+      .flat_map(ApiEscalationPolicySchedule::periods)
+      .map(|mut period| {
+        if let Some(policy) = &mut period.policy {
+          policy.team = team.clone();
+        }
+        period
+      })
+      .collect()
+  }
+}
+
+/// The fields of an [`Incident`] most applications need.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run(client: victorops::Client) -> victorops::ApiResult<()> {
+/// use victorops::model::IncidentSummary;
+///
+/// let (incidents, _) = client.get_incidents().await?;
+/// for summary in incidents.iter().map(IncidentSummary::from) {
+///   println!("#{} {} paged {:?}", summary.number, summary.phase, summary.policies);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IncidentSummary {
+  /// The incident number.
+  pub number: String,
+  /// The current phase, such as `UNACKED`, `ACKED`, or `RESOLVED`.
+  pub phase: String,
+  /// The entity display name, falling back to the entity ID.
+  pub entity: Option<String>,
+  /// The host associated with the incident.
+  pub host: Option<String>,
+  /// The service associated with the incident.
+  pub service: Option<String>,
+  /// The incident's severity, classified from its entity state.
+  pub severity: Severity,
+  /// The number of alerts in the incident.
+  pub alert_count: i32,
+  /// When the incident started.
+  pub started_at: Option<Timestamp>,
+  /// Who acknowledged the incident.
+  pub acked_by: Option<String>,
+  /// Who resolved the incident.
+  pub resolved_by: Option<String>,
+  /// The slugs of the teams that were paged.
+  pub teams: Vec<String>,
+  /// The escalation policies that were paged.
+  pub policies: Vec<Policy>,
+}
+
+impl From<&Incident> for IncidentSummary {
+  fn from(incident: &Incident) -> Self {
+    IncidentSummary {
+      number: incident.incident_number.clone().unwrap_or_default(),
+      phase: incident.current_phase.clone().unwrap_or_default(),
+      entity: incident
+        .entity_display_name
+        .clone()
+        .or_else(|| incident.entity_id.clone()),
+      host: incident.host.clone(),
+      service: incident.service.clone(),
+      severity: incident.severity(),
+      alert_count: incident.alert_count.unwrap_or(0),
+      started_at: incident.start_time.to_owned(),
+      acked_by: incident.acked_by().map(str::to_string),
+      resolved_by: incident.resolved_by().map(str::to_string),
+      teams: incident.paged_teams.clone(),
+      policies: incident.paged_policies.iter().map(Policy::from).collect(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn test_incident_summary_from_incident() {
+    let incident: Incident = serde_json::from_value(json!({
+      "incidentNumber": "1234",
+      "currentPhase": "ACKED",
+      "entityId": "disk.web-01",
+      "entityState": "CRITICAL",
+      "alertCount": 3,
+      "pagedTeams": ["team-ops"],
+      "pagedPolicies": [{
+        "policy": {"name": "Primary", "slug": "pol-primary"},
+        "team": {"name": "Ops", "slug": "team-ops"}
+      }],
+      "transitions": [{"Name": "ACKED", "By": "jdoe"}]
+    }))
+    .unwrap();
+
+    let summary = IncidentSummary::from(&incident);
+    assert_eq!(summary.number, "1234");
+    assert_eq!(summary.entity.as_deref(), Some("disk.web-01"));
+    assert_eq!(summary.severity, Severity::Critical);
+    assert_eq!(summary.acked_by.as_deref(), Some("jdoe"));
+    assert_eq!(
+      summary.policies,
+      vec![Policy {
+        name: "Primary".to_string(),
+        slug: "pol-primary".to_string(),
+        team: Some(Team {
+          name: "Ops".to_string(),
+          slug: "team-ops".to_string(),
+        }),
+      }]
+    );
+  }
+
+  #[test]
+  fn test_team_schedule_periods() {
+    let schedule: ApiTeamSchedule = serde_json::from_value(json!({
+      "team": {"name": "Ops", "slug": "team-ops"},
+      "schedules": [{
+        "policy": {"name": "Primary", "slug": "pol-primary"},
+        "schedule": [{
+          "rotationName": "Weekly",
+          "shiftName": "Days",
+          "rolls": [
+            {"start": "2026-01-01T00:00:00Z", "end": "2026-01-08T00:00:00Z", "onCallUser": {"username": "alice"}},
+            {"start": "2026-01-08T00:00:00Z", "onCallUser": {"username": "bob"}}
+          ]
+        }]
+      }]
+    }))
+    .unwrap();
+
+    let periods = schedule.periods();
+    assert_eq!(periods.len(), 1);
+    assert_eq!(periods[0].user, "alice");
+    assert_eq!(periods[0].rotation.as_deref(), Some("Weekly"));
+
+    let policy = periods[0].policy.as_ref().unwrap();
+    assert_eq!(policy.slug, "pol-primary");
+    assert_eq!(
+      policy.team.as_ref().map(|t| t.slug.as_str()),
+      Some("team-ops")
+    );
+  }
+}
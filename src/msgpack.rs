@@ -0,0 +1,115 @@
+use crate::error::{ApiResult, Error};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Encodes a value as MessagePack with named struct fields.
+///
+/// Named fields keep the encoding self-describing, which the crate's types
+/// need: untagged enums, `#[serde(flatten)]` fields, and `serde_json::Value`
+/// fields all deserialize through `deserialize_any`. Compact positional
+/// encodings (and formats that are not self-describing, such as bincode)
+/// cannot decode them.
+///
+/// # Arguments
+///
+/// * `value` - The value to encode
+///
+/// # Returns
+///
+/// The encoded bytes, or `Error::InvalidInput` if the value cannot be encoded.
+///
+/// # Examples
+///
+/// ```
+/// use victorops::msgpack;
+/// use victorops::types::Team;
+///
+/// let team = Team::new("Operations");
+/// let bytes = msgpack::to_vec(&team).unwrap();
+/// let back: Team = msgpack::from_slice(&bytes).unwrap();
+/// assert_eq!(back.name, team.name);
+/// ```
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> ApiResult<Vec<u8>> {
+  rmp_serde::to_vec_named(value)
+    .map_err(|e| Error::InvalidInput(format!("failed to encode MessagePack: {}", e)))
+}
+
+/// Decodes a value previously encoded with [`to_vec`].
+///
+/// # Arguments
+///
+/// * `bytes` - The MessagePack-encoded bytes
+///
+/// # Returns
+///
+/// The decoded value, or `Error::InvalidInput` if the bytes do not match `T`.
+pub fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> ApiResult<T> {
+  rmp_serde::from_slice(bytes)
+    .map_err(|e| Error::InvalidInput(format!("failed to decode MessagePack: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::types::*;
+  use serde_json::json;
+
+  fn assert_round_trips<T: Serialize + DeserializeOwned>(json: serde_json::Value) {
+    let value: T = serde_json::from_value(json).unwrap();
+    let back: T = from_slice(&to_vec(&value).unwrap()).unwrap();
+    assert_eq!(
+      serde_json::to_value(&back).unwrap(),
+      serde_json::to_value(&value).unwrap()
+    );
+  }
+
+  #[test]
+  fn test_types_round_trip_through_msgpack() {
+    assert_round_trips::<User>(json!({
+      "username": "jdoe", "email": "jdoe@example.com", "admin": false, "role": "alert_admin"
+    }));
+    assert_round_trips::<Contact>(
+      json!({"id": "7", "email": "jdoe@example.com", "verified": true}),
+    );
+    assert_round_trips::<Contact>(json!({"id": 8, "phone": "+15555550123", "verified": "pending"}));
+    assert_round_trips::<Admin>(json!({
+      "username": "jdoe", "_selfUrl": "/api-public/v1/user/jdoe"
+    }));
+    assert_round_trips::<IncidentResponse>(json!({"incidents": [{
+      "incidentNumber": "42",
+      "alertCount": 3,
+      "currentPhase": "ACKED",
+      "startTime": "2026-01-01T00:00:00Z",
+      "pagedTeams": ["team-ops"],
+      "pagedPolicies": [{"policy": {"name": "Primary", "slug": "pol-1"}}],
+      "transitions": [{"Name": "ACKED", "By": "jdoe", "At": "2026-01-01T00:05:00Z"}]
+    }]}));
+    assert_round_trips::<RoutingKeyResponse>(json!({
+      "routingKey": "database", "targets": ["pol-1", {"policySlug": "pol-2"}]
+    }));
+    assert_round_trips::<EscalationPolicy>(json!({
+      "name": "Primary",
+      "teamSlug": "team-ops",
+      "ignoreCustomPagingPolicies": false,
+      "steps": [{"timeout": 0, "entries": [
+        {"executionType": "rotation_group", "rotationGroup": {"slug": "rtg-1"}}
+      ]}]
+    }));
+    assert_round_trips::<crate::alerts::AlertMessage>(json!({
+      "message_type": "CRITICAL", "entity_id": "db-1/disk", "timestamp": 1704067200,
+      "region": "us-east-1", "tags": {"env": "prod", "count": 2}
+    }));
+    assert_round_trips::<crate::webhook::WebhookIncident>(json!({
+      "INCIDENT_NAME": "1042", "CURRENT_PHASE": "UNACKED", "ALERT_COUNT": 1, "CUSTOM": [1, "a"]
+    }));
+  }
+
+  #[test]
+  fn test_from_slice_rejects_mismatched_bytes() {
+    let bytes = to_vec(&json!(["not", "a", "user"])).unwrap();
+    assert!(matches!(
+      from_slice::<User>(&bytes),
+      Err(Error::InvalidInput(_))
+    ));
+  }
+}
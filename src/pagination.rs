@@ -0,0 +1,275 @@
+use crate::error::ApiResult;
+use crate::types::RequestDetails;
+use futures_util::Stream;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A single page of results from an offset/limit endpoint.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+  /// The items on this page.
+  pub items: Vec<T>,
+  /// The offset of the first item on this page.
+  pub offset: usize,
+  /// The page size that was requested.
+  pub limit: usize,
+  /// The total number of items across all pages, if the endpoint reports it.
+  pub total: Option<usize>,
+  /// The details of the request that fetched this page.
+  pub details: RequestDetails,
+}
+
+impl<T> Page<T> {
+  /// Returns true if more items are available after this page.
+  ///
+  /// When the endpoint reports a total it is authoritative; otherwise a full
+  /// page is taken to mean another page may follow.
+  pub fn has_more(&self) -> bool {
+    if self.items.is_empty() {
+      return false;
+    }
+
+    match self.total {
+      Some(total) => self.offset + self.items.len() < total,
+      None => self.items.len() >= self.limit,
+    }
+  }
+}
+
+/// A boxed future resolving to one page of results.
+pub type PageFuture<'a, T> = Pin<Box<dyn Future<Output = ApiResult<Page<T>>> + Send + 'a>>;
+
+type FetchPage<'a, T> = Box<dyn Fn(usize, usize) -> PageFuture<'a, T> + Send + Sync + 'a>;
+
+/// Walks an offset/limit endpoint page by page.
+///
+/// Pages can be pulled one at a time with [`PageStream::next_page`], gathered
+/// with [`PageStream::try_collect`], or consumed item by item as a
+/// [`Stream`]. Iteration stops after the first error.
+pub struct PageStream<'a, T> {
+  fetch: FetchPage<'a, T>,
+  offset: usize,
+  limit: usize,
+  done: bool,
+  buffered: VecDeque<T>,
+  in_flight: Option<PageFuture<'a, T>>,
+}
+
+impl<'a, T> PageStream<'a, T> {
+  /// Creates a page stream from a function that fetches the page at an offset.
+  ///
+  /// # Arguments
+  ///
+  /// * `limit` - The page size to request; clamped to at least 1
+  /// * `fetch` - Fetches the page at the given offset and limit
+  ///
+  /// # Returns
+  ///
+  /// A page stream positioned at offset zero.
+  pub fn new<F>(limit: usize, fetch: F) -> Self
+  where
+    F: Fn(usize, usize) -> PageFuture<'a, T> + Send + Sync + 'a,
+  {
+    PageStream {
+      fetch: Box::new(fetch),
+      offset: 0,
+      limit: limit.max(1),
+      done: false,
+      buffered: VecDeque::new(),
+      in_flight: None,
+    }
+  }
+
+  /// Fetches the next page.
+  ///
+  /// # Returns
+  ///
+  /// The next page, or `None` once every page has been fetched.
+  pub async fn next_page(&mut self) -> ApiResult<Option<Page<T>>> {
+    if self.done {
+      return Ok(None);
+    }
+
+    let future = match self.in_flight.take() {
+      Some(future) => future,
+      None => (self.fetch)(self.offset, self.limit),
+    };
+
+    match future.await {
+      Ok(page) => {
+        self.advance(&page);
+        Ok(Some(page))
+      }
+      Err(error) => {
+        self.done = true;
+        Err(error)
+      }
+    }
+  }
+
+  /// Fetches every remaining page and concatenates their items.
+  ///
+  /// # Returns
+  ///
+  /// All remaining items in order, or the first error encountered.
+  pub async fn try_collect(mut self) -> ApiResult<Vec<T>> {
+    let mut items: Vec<T> = self.buffered.drain(..).collect();
+    while let Some(page) = self.next_page().await? {
+      items.extend(page.items);
+    }
+
+    Ok(items)
+  }
+
+  fn advance(&mut self, page: &Page<T>) {
+    self.offset = page.offset + page.items.len();
+    self.done = !page.has_more();
+  }
+}
+
+// No field is structurally pinned; in-flight futures are boxed.
+impl<T> Unpin for PageStream<'_, T> {}
+
+impl<T> Stream for PageStream<'_, T> {
+  type Item = ApiResult<T>;
+
+  fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    loop {
+      if let Some(item) = self.buffered.pop_front() {
+        return Poll::Ready(Some(Ok(item)));
+      }
+
+      if self.done {
+        return Poll::Ready(None);
+      }
+
+      let this = &mut *self;
+      let future = this
+        .in_flight
+        .get_or_insert_with(|| (this.fetch)(this.offset, this.limit));
+
+      match future.as_mut().poll(cx) {
+        Poll::Pending => return Poll::Pending,
+        Poll::Ready(Ok(page)) => {
+          this.in_flight = None;
+          this.advance(&page);
+          this.buffered.extend(page.items);
+        }
+        Poll::Ready(Err(error)) => {
+          this.in_flight = None;
+          this.done = true;
+          return Poll::Ready(Some(Err(error)));
+        }
+      }
+    }
+  }
+}
+
+impl<T> std::fmt::Debug for PageStream<'_, T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("PageStream")
+      .field("offset", &self.offset)
+      .field("limit", &self.limit)
+      .field("done", &self.done)
+      .field("buffered", &self.buffered.len())
+      .finish_non_exhaustive()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::error::Error;
+  use futures_util::StreamExt;
+  use std::sync::Arc;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  fn details() -> RequestDetails {
+    RequestDetails {
+      status_code: 200,
+      request_body: "{}".to_string(),
+      response_bytes: bytes::Bytes::from_static(b"{}"),
+      ..Default::default()
+    }
+  }
+
+  fn numbers(total: usize, limit: usize, calls: Arc<AtomicUsize>) -> PageStream<'static, usize> {
+    PageStream::new(limit, move |offset, limit| {
+      calls.fetch_add(1, Ordering::SeqCst);
+      Box::pin(async move {
+        Ok(Page {
+          items: (offset..total.min(offset + limit)).collect(),
+          offset,
+          limit,
+          total: Some(total),
+          details: details(),
+        })
+      })
+    })
+  }
+
+  #[tokio::test]
+  async fn test_next_page_walks_offsets() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut pages = numbers(5, 2, calls.clone());
+
+    let first = pages.next_page().await.unwrap().unwrap();
+    assert_eq!(first.items, vec![0, 1]);
+    assert!(first.has_more());
+
+    let second = pages.next_page().await.unwrap().unwrap();
+    assert_eq!((second.offset, second.items), (2, vec![2, 3]));
+
+    let third = pages.next_page().await.unwrap().unwrap();
+    assert_eq!(third.items, vec![4]);
+    assert!(!third.has_more());
+
+    assert!(pages.next_page().await.unwrap().is_none());
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+  }
+
+  #[tokio::test]
+  async fn test_try_collect_and_stream() {
+    let collected = numbers(7, 3, Arc::new(AtomicUsize::new(0)))
+      .try_collect()
+      .await
+      .unwrap();
+    assert_eq!(collected, (0..7).collect::<Vec<_>>());
+
+    let streamed: Vec<usize> = numbers(4, 10, Arc::new(AtomicUsize::new(0)))
+      .map(Result::unwrap)
+      .collect()
+      .await;
+    assert_eq!(streamed, vec![0, 1, 2, 3]);
+  }
+
+  #[tokio::test]
+  async fn test_without_total_stops_on_short_page() {
+    let pages = PageStream::new(2, |offset, limit| {
+      Box::pin(async move {
+        Ok(Page {
+          items: (offset..3.min(offset + limit)).collect::<Vec<usize>>(),
+          offset,
+          limit,
+          total: None,
+          details: details(),
+        })
+      })
+    });
+
+    assert_eq!(pages.try_collect().await.unwrap(), vec![0, 1, 2]);
+  }
+
+  #[tokio::test]
+  async fn test_stream_stops_after_error() {
+    let pages: PageStream<'_, usize> = PageStream::new(2, |_, _| {
+      Box::pin(async { Err(Error::InvalidInput("boom".to_string())) })
+    });
+
+    let results: Vec<ApiResult<usize>> = pages.collect().await;
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results[0], Err(Error::InvalidInput(_))));
+  }
+}
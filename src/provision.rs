@@ -0,0 +1,773 @@
+use crate::error::{ApiResult, Error};
+use crate::migrate::OrgSnapshot;
+use crate::types::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A declarative description of teams, escalation policies, and routing keys.
+///
+/// Load a spec from JSON with [`ProvisioningSpec::from_json`] or, with the
+/// `toml` feature, from TOML with [`ProvisioningSpec::from_toml`]:
+///
+/// ```toml
+/// [[teams]]
+/// name = "Database"
+/// members = ["alice", "bob"]
+///
+/// [[escalation_policies]]
+/// name = "Database Primary"
+/// team = "Database"
+/// steps = [
+///   { timeout = 0, targets = [{ rotation_group = "rtg-db-primary" }] },
+///   { timeout = 15, targets = [{ user = "alice" }] },
+/// ]
+///
+/// [[routing_keys]]
+/// key = "database"
+/// policies = ["Database/Database Primary"]
+/// ```
+///
+/// The spec derives serde traits, so other formats such as YAML can be loaded
+/// with their own serde crates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ProvisioningSpec {
+  /// The teams that should exist, with the members they should contain.
+  #[serde(default)]
+  pub teams: Vec<TeamSpec>,
+  /// The escalation policies that should exist.
+  #[serde(default)]
+  pub escalation_policies: Vec<PolicySpec>,
+  /// The routing keys that should exist.
+  #[serde(default)]
+  pub routing_keys: Vec<RoutingKeySpec>,
+}
+
+/// A team that should exist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TeamSpec {
+  /// The name of the team.
+  pub name: String,
+  /// The usernames that should be members of the team.
+  #[serde(default)]
+  pub members: Vec<String>,
+}
+
+/// An escalation policy that should exist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PolicySpec {
+  /// The name of the escalation policy.
+  pub name: String,
+  /// The name of the team that owns the escalation policy.
+  pub team: String,
+  /// Whether to ignore custom paging policies.
+  #[serde(default)]
+  pub ignore_custom_paging_policies: bool,
+  /// The escalation steps, in order.
+  pub steps: Vec<StepSpec>,
+}
+
+/// An escalation step within a [`PolicySpec`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StepSpec {
+  /// The timeout before escalating to the next step.
+  pub timeout: i32,
+  /// The targets notified by this step.
+  pub targets: Vec<TargetSpec>,
+}
+
+/// A target notified by an escalation step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TargetSpec {
+  /// Page a specific user by username.
+  User(String),
+  /// Page whoever is on-call for a rotation group, by slug.
+  RotationGroup(String),
+  /// Call a webhook, by slug.
+  Webhook(String),
+  /// Send an email to an address.
+  Email(String),
+  /// Escalate to another escalation policy, by slug.
+  Policy(String),
+}
+
+/// A routing key that should exist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RoutingKeySpec {
+  /// The routing key value.
+  pub key: String,
+  /// The escalation policies the key should route to, as `team/policy`.
+  ///
+  /// Policy names are only unique within a team, so a bare policy name is
+  /// accepted only when exactly one team has a policy by that name. The team
+  /// name ends at the first `/`.
+  pub policies: Vec<String>,
+}
+
+/// A change needed to bring the organization in line with a spec.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ProvisionAction {
+  /// Create a missing team.
+  CreateTeam {
+    /// The name of the team.
+    team: String,
+  },
+  /// Add a missing member to a team.
+  AddTeamMember {
+    /// The name of the team.
+    team: String,
+    /// The username to add.
+    username: String,
+  },
+  /// Create a missing escalation policy.
+  CreatePolicy {
+    /// The name of the owning team.
+    team: String,
+    /// The name of the escalation policy.
+    policy: String,
+  },
+  /// Replace an existing escalation policy that differs from the spec.
+  ///
+  /// The API offers no way to update a policy in place, so the policy is
+  /// deleted and created again, which gives it a new slug.
+  RecreatePolicy {
+    /// The name of the owning team.
+    team: String,
+    /// The name of the escalation policy.
+    policy: String,
+  },
+  /// An existing escalation policy differs from the spec, but routing keys or
+  /// other policies refer to it by slug.
+  ///
+  /// Recreating the policy would change its slug and leave those references
+  /// dangling, and routing keys cannot be updated, so drift is reported
+  /// rather than applied.
+  PolicyDrift {
+    /// The name of the owning team.
+    team: String,
+    /// The name of the escalation policy.
+    policy: String,
+  },
+  /// Create a missing routing key.
+  CreateRoutingKey {
+    /// The routing key value.
+    key: String,
+  },
+  /// An existing routing key routes to different policies than the spec.
+  ///
+  /// The API offers no way to update or delete a routing key, so drift is
+  /// reported rather than applied.
+  RoutingKeyDrift {
+    /// The routing key value.
+    key: String,
+  },
+}
+
+/// The outcome of applying a [`ProvisioningSpec`].
+///
+/// Applying stops at the first action that fails. The report still lists
+/// what was applied before the failure, so a partial run can be diagnosed
+/// and the spec applied again once the problem is fixed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ProvisionReport {
+  /// Every action the plan called for, including drift that is only reported.
+  pub planned: Vec<ProvisionAction>,
+  /// The actions that were performed, in order.
+  pub applied: Vec<ProvisionAction>,
+  /// The details of every request made, including the initial snapshot.
+  pub details: Vec<RequestDetails>,
+  /// The action that failed and why, if applying stopped early.
+  pub failure: Option<(ProvisionAction, Error)>,
+}
+
+impl ProvisionReport {
+  /// Returns true if every applicable planned action was performed.
+  pub fn is_complete(&self) -> bool {
+    self.failure.is_none()
+  }
+}
+
+/// The escalation policies a spec's routing keys can refer to, by owning team
+/// name and policy name.
+///
+/// Holds the organization's existing policies and the spec's policies, with
+/// the slug of each one that exists.
+#[derive(Debug, Default)]
+pub(crate) struct PolicyIndex {
+  policies: BTreeMap<(String, String), Option<String>>,
+}
+
+impl PolicyIndex {
+  /// Indexes the policies in a snapshot and a spec.
+  pub(crate) fn new(spec: &ProvisioningSpec, snapshot: &OrgSnapshot) -> Self {
+    let mut index = PolicyIndex::default();
+
+    for policy in &snapshot.escalation_policies {
+      let team = snapshot
+        .teams
+        .iter()
+        .find(|t| t.slug.as_deref() == Some(policy.team_id.as_str()))
+        .and_then(|t| t.name.clone())
+        .unwrap_or_else(|| policy.team_id.clone());
+      index.insert(&team, &policy.name, Some(policy.id.clone()));
+    }
+
+    for policy in &spec.escalation_policies {
+      index
+        .policies
+        .entry((policy.team.clone(), policy.name.clone()))
+        .or_default();
+    }
+
+    index
+  }
+
+  /// Returns the slug of a team's policy, if it exists.
+  pub(crate) fn get(&self, team: &str, policy: &str) -> Option<&str> {
+    self
+      .policies
+      .get(&(team.to_string(), policy.to_string()))
+      .and_then(Option::as_deref)
+  }
+
+  /// Records a policy and, if it exists, its slug.
+  pub(crate) fn insert(&mut self, team: &str, policy: &str, slug: Option<String>) {
+    self
+      .policies
+      .insert((team.to_string(), policy.to_string()), slug);
+  }
+
+  /// Resolves a `team/policy` or bare policy name reference.
+  ///
+  /// # Returns
+  ///
+  /// The policy's slug, `None` if the policy has not been created yet, or
+  /// `Error::InvalidInput` if the reference is unknown or ambiguous.
+  pub(crate) fn resolve(&self, reference: &str) -> ApiResult<Option<&str>> {
+    let (team, name) = match reference.split_once('/') {
+      Some((team, name)) => (Some(team), name),
+      None => (None, reference),
+    };
+
+    let matches: Vec<_> = self
+      .policies
+      .iter()
+      .filter(|((t, n), _)| n == name && team.is_none_or(|team| t == team))
+      .collect();
+
+    match matches.as_slice() {
+      [] => Err(Error::InvalidInput(format!(
+        "Unknown escalation policy in provisioning spec: {}",
+        reference
+      ))),
+      [(_, slug)] => Ok(slug.as_deref()),
+      _ => Err(Error::InvalidInput(format!(
+        "Escalation policy {:?} exists in teams {}; use team/policy",
+        reference,
+        matches
+          .iter()
+          .map(|((team, _), _)| team.as_str())
+          .collect::<Vec<_>>()
+          .join(", ")
+      ))),
+    }
+  }
+}
+
+impl ProvisionAction {
+  /// Returns whether applying the spec will perform this action.
+  pub fn is_applicable(&self) -> bool {
+    !matches!(
+      self,
+      ProvisionAction::PolicyDrift { .. } | ProvisionAction::RoutingKeyDrift { .. }
+    )
+  }
+}
+
+impl TargetSpec {
+  /// Converts the target into an escalation policy step entry.
+  pub fn to_entry(&self) -> EscalationPolicyStepEntry {
+    let target = match self.clone() {
+      TargetSpec::User(username) => EscalationTarget::User(username),
+      TargetSpec::RotationGroup(slug) => EscalationTarget::RotationGroup(slug),
+      TargetSpec::Webhook(slug) => EscalationTarget::Webhook(slug),
+      TargetSpec::Email(address) => EscalationTarget::Email(address),
+      TargetSpec::Policy(slug) => EscalationTarget::Policy(slug),
+    };
+    target.into()
+  }
+
+  /// Reads a target back from an escalation policy step entry.
+  ///
+  /// Returns `None` for entries whose target type is not understood.
+  pub fn from_entry(entry: &EscalationPolicyStepEntry) -> Option<Self> {
+    let field = |map: &Option<BTreeMap<String, String>>, key: &str| {
+      map.as_ref().and_then(|m| m.get(key).cloned())
+    };
+
+    field(&entry.user, "username")
+      .map(TargetSpec::User)
+      .or_else(|| field(&entry.rotation_group, "slug").map(TargetSpec::RotationGroup))
+      .or_else(|| field(&entry.webhook, "slug").map(TargetSpec::Webhook))
+      .or_else(|| field(&entry.email, "address").map(TargetSpec::Email))
+      .or_else(|| field(&entry.target_policy, "policySlug").map(TargetSpec::Policy))
+  }
+}
+
+impl PolicySpec {
+  /// Builds the escalation policy to create for this spec.
+  ///
+  /// # Arguments
+  ///
+  /// * `team_slug` - The slug of the owning team
+  pub fn to_policy(&self, team_slug: &str) -> EscalationPolicy {
+    EscalationPolicy {
+      name: self.name.clone(),
+      team_id: team_slug.to_string(),
+      ignore_custom_paging_policies: self.ignore_custom_paging_policies,
+      steps: self
+        .steps
+        .iter()
+        .map(|step| EscalationPolicySteps {
+          timeout: step.timeout,
+          entries: step.targets.iter().map(TargetSpec::to_entry).collect(),
+        })
+        .collect(),
+      id: String::new(),
+    }
+  }
+
+  fn matches(&self, policy: &EscalationPolicy) -> bool {
+    if self.ignore_custom_paging_policies != policy.ignore_custom_paging_policies
+      || self.steps.len() != policy.steps.len()
+    {
+      return false;
+    }
+
+    self
+      .steps
+      .iter()
+      .zip(&policy.steps)
+      .all(|(desired, actual)| {
+        let targets: Option<Vec<TargetSpec>> =
+          actual.entries.iter().map(TargetSpec::from_entry).collect();
+        desired.timeout == actual.timeout && targets.as_ref() == Some(&desired.targets)
+      })
+  }
+}
+
+impl ProvisioningSpec {
+  /// Parses a spec from JSON.
+  pub fn from_json(json: &str) -> ApiResult<Self> {
+    Ok(serde_json::from_str(json)?)
+  }
+
+  /// Parses a spec from TOML.
+  ///
+  /// # Returns
+  ///
+  /// The spec, or `Error::InvalidInput` describing where the TOML is invalid.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use victorops::provision::ProvisioningSpec;
+  ///
+  /// let spec = ProvisioningSpec::from_toml(
+  ///   r#"
+  ///   [[teams]]
+  ///   name = "Database"
+  ///   members = ["alice"]
+  ///   "#,
+  /// )
+  /// .unwrap();
+  /// assert_eq!(spec.teams[0].members, vec!["alice"]);
+  /// ```
+  #[cfg(feature = "toml")]
+  pub fn from_toml(toml: &str) -> ApiResult<Self> {
+    toml::from_str(toml)
+      .map_err(|e| Error::InvalidInput(format!("Invalid provisioning spec: {}", e)))
+  }
+
+  /// Computes the actions needed to bring a snapshot in line with the spec.
+  ///
+  /// Provisioning is additive: teams, members, policies, and routing keys
+  /// missing from the organization are created, but nothing absent from the
+  /// spec is removed. Policies that differ from the spec are recreated unless
+  /// something refers to them by slug.
+  ///
+  /// # Arguments
+  ///
+  /// * `snapshot` - The current state of the organization
+  ///
+  /// # Returns
+  ///
+  /// The planned actions, or `Error::InvalidInput` if a routing key refers to
+  /// an unknown or ambiguous escalation policy.
+  pub fn plan(&self, snapshot: &OrgSnapshot) -> ApiResult<Vec<ProvisionAction>> {
+    let mut actions = Vec::new();
+    let team_slug = |name: &str| {
+      snapshot
+        .teams
+        .iter()
+        .find(|t| t.name.as_deref() == Some(name))
+        .and_then(|t| t.slug.clone())
+    };
+
+    for team in &self.teams {
+      let current: BTreeSet<String> = match team_slug(&team.name) {
+        Some(slug) => snapshot
+          .members
+          .get(&slug)
+          .into_iter()
+          .flatten()
+          .filter_map(|u| u.username.as_ref().map(|n| n.to_lowercase()))
+          .collect(),
+        None => {
+          actions.push(ProvisionAction::CreateTeam {
+            team: team.name.clone(),
+          });
+          BTreeSet::new()
+        }
+      };
+
+      for username in &team.members {
+        if !current.contains(&username.to_lowercase()) {
+          actions.push(ProvisionAction::AddTeamMember {
+            team: team.name.clone(),
+            username: username.clone(),
+          });
+        }
+      }
+    }
+
+    for policy in &self.escalation_policies {
+      let slug = team_slug(&policy.team);
+      let existing = snapshot
+        .escalation_policies
+        .iter()
+        .find(|p| p.name == policy.name && Some(&p.team_id) == slug.as_ref());
+
+      match existing {
+        None => actions.push(ProvisionAction::CreatePolicy {
+          team: policy.team.clone(),
+          policy: policy.name.clone(),
+        }),
+        Some(existing) if !policy.matches(existing) => {
+          let team = policy.team.clone();
+          let policy = policy.name.clone();
+          actions.push(if snapshot_refers_to(snapshot, &existing.id) {
+            ProvisionAction::PolicyDrift { team, policy }
+          } else {
+            ProvisionAction::RecreatePolicy { team, policy }
+          });
+        }
+        Some(_) => {}
+      }
+    }
+
+    let policies = PolicyIndex::new(self, snapshot);
+
+    for key in &self.routing_keys {
+      let desired = key
+        .policies
+        .iter()
+        .map(|reference| policies.resolve(reference))
+        .collect::<ApiResult<Option<BTreeSet<&str>>>>()?;
+
+      let existing = snapshot
+        .routing_keys
+        .iter()
+        .find(|k| k.routing_key.as_deref() == Some(key.key.as_str()));
+
+      let Some(existing) = existing else {
+        actions.push(ProvisionAction::CreateRoutingKey {
+          key: key.key.clone(),
+        });
+        continue;
+      };
+
+      let current: BTreeSet<&str> = existing
+        .targets
+        .iter()
+        .filter_map(|t| t.policy_slug().map(PolicySlug::as_str))
+        .collect();
+
+      if desired != Some(current) {
+        actions.push(ProvisionAction::RoutingKeyDrift {
+          key: key.key.clone(),
+        });
+      }
+    }
+
+    Ok(actions)
+  }
+}
+
+/// Returns whether a routing key or another policy's step targets a policy.
+fn snapshot_refers_to(snapshot: &OrgSnapshot, slug: &str) -> bool {
+  let routed = snapshot.routing_keys.iter().any(|key| {
+    key
+      .targets
+      .iter()
+      .any(|t| t.policy_slug().map(PolicySlug::as_str) == Some(slug))
+  });
+  let escalated = snapshot
+    .escalation_policies
+    .iter()
+    .flat_map(|p| &p.steps)
+    .flat_map(|step| &step.entries)
+    .any(|entry| TargetSpec::from_entry(entry) == Some(TargetSpec::Policy(slug.to_string())));
+
+  routed || escalated
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::BTreeMap;
+
+  fn spec() -> ProvisioningSpec {
+    ProvisioningSpec::from_json(
+      r#"{
+        "teams": [
+          {"name": "Database", "members": ["alice", "Bob"]},
+          {"name": "Web", "members": ["carol"]}
+        ],
+        "escalation_policies": [
+          {
+            "name": "Database Primary",
+            "team": "Database",
+            "steps": [
+              {"timeout": 0, "targets": [{"rotation_group": "rtg-db"}]},
+              {"timeout": 15, "targets": [{"user": "alice"}]}
+            ]
+          },
+          {
+            "name": "Web Primary",
+            "team": "Web",
+            "steps": [{"timeout": 0, "targets": [{"user": "carol"}]}]
+          }
+        ],
+        "routing_keys": [
+          {"key": "database", "policies": ["Database/Database Primary"]},
+          {"key": "web", "policies": ["Web Primary"]}
+        ]
+      }"#,
+    )
+    .unwrap()
+  }
+
+  fn snapshot() -> OrgSnapshot {
+    let database = spec().escalation_policies[0].to_policy("team-db");
+    let mut database = EscalationPolicy {
+      id: "pol-db".to_string(),
+      ..database
+    };
+    database.steps[1].timeout = 30;
+
+    OrgSnapshot {
+      teams: vec![serde_json::from_str(r#"{"name": "Database", "slug": "team-db"}"#).unwrap()],
+      members: BTreeMap::from([(
+        "team-db".to_string(),
+        vec![serde_json::from_str(r#"{"username": "bob"}"#).unwrap()],
+      )]),
+      schedules: vec![],
+      escalation_policies: vec![database],
+      routing_keys: vec![RoutingKeyResponse {
+        routing_key: Some("database".to_string()),
+        targets: vec![RoutingTarget::policy("pol-db")],
+      }],
+    }
+  }
+
+  #[test]
+  fn test_plan_creates_missing_and_reports_drift() {
+    let actions = spec().plan(&snapshot()).unwrap();
+
+    assert_eq!(
+      actions,
+      vec![
+        ProvisionAction::AddTeamMember {
+          team: "Database".to_string(),
+          username: "alice".to_string(),
+        },
+        ProvisionAction::CreateTeam {
+          team: "Web".to_string(),
+        },
+        ProvisionAction::AddTeamMember {
+          team: "Web".to_string(),
+          username: "carol".to_string(),
+        },
+        ProvisionAction::PolicyDrift {
+          team: "Database".to_string(),
+          policy: "Database Primary".to_string(),
+        },
+        ProvisionAction::CreatePolicy {
+          team: "Web".to_string(),
+          policy: "Web Primary".to_string(),
+        },
+        ProvisionAction::CreateRoutingKey {
+          key: "web".to_string(),
+        },
+      ]
+    );
+    assert!(!actions[3].is_applicable());
+    assert!(actions[4].is_applicable());
+  }
+
+  #[test]
+  fn test_plan_recreates_unreferenced_drifted_policy() {
+    let mut snapshot = snapshot();
+    snapshot.routing_keys.clear();
+
+    let actions = spec().plan(&snapshot).unwrap();
+    let recreate = ProvisionAction::RecreatePolicy {
+      team: "Database".to_string(),
+      policy: "Database Primary".to_string(),
+    };
+    assert!(actions.contains(&recreate));
+    assert!(recreate.is_applicable());
+
+    let mut escalating = spec().escalation_policies[1].to_policy("team-db");
+    escalating.name = "Database Fallback".to_string();
+    escalating.steps[0].entries = vec![TargetSpec::Policy("pol-db".to_string()).to_entry()];
+    snapshot.escalation_policies.push(escalating);
+    assert!(!spec().plan(&snapshot).unwrap().contains(&recreate));
+  }
+
+  #[cfg(feature = "toml")]
+  #[test]
+  fn test_from_toml_matches_json() {
+    let parsed = ProvisioningSpec::from_toml(
+      r#"
+      [[teams]]
+      name = "Database"
+      members = ["alice", "Bob"]
+
+      [[teams]]
+      name = "Web"
+      members = ["carol"]
+
+      [[escalation_policies]]
+      name = "Database Primary"
+      team = "Database"
+      steps = [
+        { timeout = 0, targets = [{ rotation_group = "rtg-db" }] },
+        { timeout = 15, targets = [{ user = "alice" }] },
+      ]
+
+      [[escalation_policies]]
+      name = "Web Primary"
+      team = "Web"
+      steps = [{ timeout = 0, targets = [{ user = "carol" }] }]
+
+      [[routing_keys]]
+      key = "database"
+      policies = ["Database/Database Primary"]
+
+      [[routing_keys]]
+      key = "web"
+      policies = ["Web Primary"]
+      "#,
+    )
+    .unwrap();
+    assert_eq!(parsed, spec());
+
+    assert!(matches!(
+      ProvisioningSpec::from_toml("[[teams]]\nmembers = 3"),
+      Err(Error::InvalidInput(_))
+    ));
+  }
+
+  #[test]
+  fn test_plan_is_empty_when_in_sync() {
+    let spec = ProvisioningSpec {
+      teams: vec![TeamSpec {
+        name: "Database".to_string(),
+        members: vec!["BOB".to_string()],
+      }],
+      escalation_policies: vec![],
+      routing_keys: vec![RoutingKeySpec {
+        key: "database".to_string(),
+        policies: vec!["Database Primary".to_string()],
+      }],
+    };
+
+    assert!(spec.plan(&snapshot()).unwrap().is_empty());
+  }
+
+  #[test]
+  fn test_plan_resolves_policies_by_team() {
+    let mut snapshot = snapshot();
+    snapshot
+      .teams
+      .push(serde_json::from_str(r#"{"name": "Web", "slug": "team-web"}"#).unwrap());
+    for (team, slug) in [
+      ("team-db", "pol-db-primary"),
+      ("team-web", "pol-web-primary"),
+    ] {
+      snapshot.escalation_policies.push(EscalationPolicy {
+        id: slug.to_string(),
+        ..PolicySpec {
+          name: "Primary".to_string(),
+          team: String::new(),
+          ignore_custom_paging_policies: false,
+          steps: vec![],
+        }
+        .to_policy(team)
+      });
+    }
+    snapshot.routing_keys[0].targets = vec![RoutingTarget::policy("pol-web-primary")];
+
+    let mut spec = ProvisioningSpec {
+      routing_keys: vec![RoutingKeySpec {
+        key: "database".to_string(),
+        policies: vec!["Database/Primary".to_string()],
+      }],
+      ..Default::default()
+    };
+    assert_eq!(
+      spec.plan(&snapshot).unwrap(),
+      vec![ProvisionAction::RoutingKeyDrift {
+        key: "database".to_string(),
+      }]
+    );
+
+    spec.routing_keys[0].policies = vec!["Web/Primary".to_string()];
+    assert!(spec.plan(&snapshot).unwrap().is_empty());
+
+    spec.routing_keys[0].policies = vec!["Primary".to_string()];
+    assert!(matches!(
+      spec.plan(&snapshot),
+      Err(Error::InvalidInput(message)) if message.contains("Database, Web")
+    ));
+
+    spec.routing_keys[0].policies = vec!["Missing".to_string()];
+    assert!(matches!(spec.plan(&snapshot), Err(Error::InvalidInput(_))));
+  }
+
+  #[test]
+  fn test_target_spec_entry_round_trip() {
+    let targets = vec![
+      TargetSpec::User("alice".to_string()),
+      TargetSpec::RotationGroup("rtg".to_string()),
+      TargetSpec::Webhook("hook".to_string()),
+      TargetSpec::Email("ops@example.com".to_string()),
+      TargetSpec::Policy("pol".to_string()),
+    ];
+
+    for target in targets {
+      assert_eq!(TargetSpec::from_entry(&target.to_entry()), Some(target));
+    }
+  }
+}
@@ -0,0 +1,473 @@
+use crate::error::ApiResult;
+use crate::types::*;
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// Deserializes an optional string, borrowing it from the input when it
+/// contains no escape sequences.
+fn borrow_opt<'de: 'a, 'a, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<Option<Cow<'a, str>>, D::Error> {
+  #[derive(Deserialize)]
+  struct Borrowed<'a>(#[serde(borrow)] Cow<'a, str>);
+
+  Ok(Option::<Borrowed>::deserialize(deserializer)?.map(|b| b.0))
+}
+
+/// Deserializes a list of strings, borrowing each one where possible.
+fn borrow_vec<'de: 'a, 'a, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<Vec<Cow<'a, str>>, D::Error> {
+  #[derive(Deserialize)]
+  struct Borrowed<'a>(#[serde(borrow)] Cow<'a, str>);
+
+  Ok(
+    Option::<Vec<Borrowed>>::deserialize(deserializer)?
+      .unwrap_or_default()
+      .into_iter()
+      .map(|b| b.0)
+      .collect(),
+  )
+}
+
+fn owned(value: &Option<Cow<'_, str>>) -> Option<String> {
+  value.as_deref().map(str::to_string)
+}
+
+fn timestamp(value: &Option<Cow<'_, str>>) -> Option<Timestamp> {
+  value.as_deref().and_then(|v| v.parse().ok())
+}
+
+/// A borrowed view of an [`Incident`].
+///
+/// String fields point into the response body instead of being copied, so
+/// scanning a large incident list allocates only for strings that contain
+/// JSON escapes. Timestamps are kept as their raw RFC 3339 text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RawIncident<'a> {
+  /// The number of alerts in this incident.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "alertCount")]
+  pub alert_count: Option<i32>,
+  /// The current phase or state of the incident.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "currentPhase"
+  )]
+  pub current_phase: Option<Cow<'a, str>>,
+  /// The display name of the entity that triggered the incident.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "entityDisplayName"
+  )]
+  pub entity_display_name: Option<Cow<'a, str>>,
+  /// The unique identifier of the entity that triggered the incident.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "entityId"
+  )]
+  pub entity_id: Option<Cow<'a, str>>,
+  /// The state of the entity that triggered the incident.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "entityState"
+  )]
+  pub entity_state: Option<Cow<'a, str>>,
+  /// The type of entity that triggered the incident.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "entityType"
+  )]
+  pub entity_type: Option<Cow<'a, str>>,
+  /// The host associated with the incident.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub host: Option<Cow<'a, str>>,
+  /// The incident number or identifier.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "incidentNumber"
+  )]
+  pub incident_number: Option<Cow<'a, str>>,
+  /// The ID of the last alert in this incident.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "lastAlertId"
+  )]
+  pub last_alert_id: Option<Cow<'a, str>>,
+  /// The timestamp of the last alert in this incident.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "lastAlertTime"
+  )]
+  pub last_alert_time: Option<Cow<'a, str>>,
+  /// The service associated with the incident.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub service: Option<Cow<'a, str>>,
+  /// The timestamp when the incident started.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "startTime"
+  )]
+  pub start_time: Option<Cow<'a, str>>,
+  /// The list of teams that were paged for this incident.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_vec",
+    skip_serializing_if = "Vec::is_empty",
+    rename = "pagedTeams"
+  )]
+  pub paged_teams: Vec<Cow<'a, str>>,
+  /// The list of users that were paged for this incident.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_vec",
+    skip_serializing_if = "Vec::is_empty",
+    rename = "pagedUsers"
+  )]
+  pub paged_users: Vec<Cow<'a, str>>,
+  /// The state transitions that occurred during this incident.
+  #[serde(default, borrow, skip_serializing_if = "Vec::is_empty")]
+  pub transitions: Vec<RawTransition<'a>>,
+}
+
+impl RawIncident<'_> {
+  /// Classifies the incident's severity from its entity state.
+  pub fn severity(&self) -> Severity {
+    self
+      .entity_state
+      .as_deref()
+      .map(Severity::from_state)
+      .unwrap_or(Severity::Unknown)
+  }
+
+  /// Copies the view into an owned [`Incident`].
+  ///
+  /// Timestamps that fail to parse become `None`. Paged policies are not part
+  /// of the view, so the result has none.
+  pub fn to_incident(&self) -> Incident {
+    Incident {
+      alert_count: self.alert_count,
+      current_phase: owned(&self.current_phase),
+      entity_display_name: owned(&self.entity_display_name),
+      entity_id: owned(&self.entity_id),
+      entity_state: owned(&self.entity_state),
+      entity_type: owned(&self.entity_type),
+      host: owned(&self.host),
+      incident_number: owned(&self.incident_number),
+      last_alert_id: owned(&self.last_alert_id),
+      last_alert_time: timestamp(&self.last_alert_time),
+      service: owned(&self.service),
+      start_time: timestamp(&self.start_time),
+      paged_teams: self.paged_teams.iter().map(|t| t.to_string()).collect(),
+      paged_users: self.paged_users.iter().map(|u| u.to_string()).collect(),
+      paged_policies: Vec::new(),
+      transitions: self
+        .transitions
+        .iter()
+        .map(RawTransition::to_transition)
+        .collect(),
+    }
+  }
+}
+
+/// A borrowed view of a [`Transition`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RawTransition<'a> {
+  /// The name of the transition.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "Name"
+  )]
+  pub name: Option<Cow<'a, str>>,
+  /// When the transition occurred.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "At"
+  )]
+  pub at: Option<Cow<'a, str>>,
+  /// Who performed the transition.
+  #[serde(
+    default,
+    borrow,
+    deserialize_with = "borrow_opt",
+    skip_serializing_if = "Option::is_none",
+    rename = "By"
+  )]
+  pub by: Option<Cow<'a, str>>,
+}
+
+impl RawTransition<'_> {
+  /// Copies the view into an owned [`Transition`].
+  pub fn to_transition(&self) -> Transition {
+    Transition {
+      name: owned(&self.name),
+      at: timestamp(&self.at),
+      message: None,
+      by: owned(&self.by),
+      manually: None,
+      alert_id: None,
+      alert_url: None,
+    }
+  }
+}
+
+/// A borrowed view of an [`IncidentResponse`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run(client: victorops::Client) -> victorops::ApiResult<()> {
+/// use victorops::raw::RawIncidentResponse;
+///
+/// let details = client.get_incidents_raw().await?;
+/// let response = RawIncidentResponse::from_slice(&details.response_bytes)?;
+///
+/// for incident in &response.incidents {
+///   println!("{:?} {:?}", incident.incident_number, incident.current_phase);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RawIncidentResponse<'a> {
+  /// The incidents in the response.
+  #[serde(default, borrow, skip_serializing_if = "Vec::is_empty")]
+  pub incidents: Vec<RawIncident<'a>>,
+}
+
+impl<'a> RawIncidentResponse<'a> {
+  /// Parses a `GET /v1/incidents` response body without copying its strings.
+  ///
+  /// # Arguments
+  ///
+  /// * `body` - The raw response body, which the result borrows from
+  pub fn from_slice(body: &'a [u8]) -> ApiResult<Self> {
+    Ok(serde_json::from_slice(body)?)
+  }
+}
+
+/// A borrowed view of an [`IncidentHistoryPage`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RawIncidentHistoryPage<'a> {
+  /// The offset of the first incident on this page.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub offset: Option<usize>,
+  /// The page size that was requested.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub limit: Option<usize>,
+  /// The total number of incidents matching the query.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub total: Option<usize>,
+  /// The incidents on this page.
+  #[serde(default, borrow, skip_serializing_if = "Vec::is_empty")]
+  pub incidents: Vec<RawIncident<'a>>,
+}
+
+impl<'a> RawIncidentHistoryPage<'a> {
+  /// Parses a reporting API incident history page without copying its strings.
+  ///
+  /// # Arguments
+  ///
+  /// * `body` - The raw response body, which the result borrows from
+  pub fn from_slice(body: &'a [u8]) -> ApiResult<Self> {
+    Ok(serde_json::from_slice(body)?)
+  }
+}
+
+/// A deserialized value together with the JSON it was parsed from.
+///
+/// Wrap any response type, or the items of a list, to keep exactly what the
+/// API returned, including fields the typed value does not model. The wrapper
+/// dereferences to the typed value and serializes back to the raw JSON.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run(client: victorops::Client) -> victorops::ApiResult<()> {
+/// use victorops::IncidentResponse;
+/// use victorops::raw::WithRaw;
+///
+/// let (incidents, _): (WithRaw<IncidentResponse>, _) =
+///   client.get_json("v1/incidents", &[]).await?;
+/// println!("{} incidents", incidents.incidents.len());
+/// println!("audit: {}", incidents.raw);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithRaw<T> {
+  /// The typed value.
+  pub value: T,
+  /// The JSON the value was parsed from.
+  pub raw: Value,
+}
+
+impl<T> WithRaw<T> {
+  /// Returns the typed value, discarding the raw JSON.
+  pub fn into_inner(self) -> T {
+    self.value
+  }
+
+  /// Returns the typed value and the raw JSON.
+  pub fn into_parts(self) -> (T, Value) {
+    (self.value, self.raw)
+  }
+}
+
+impl<T> Deref for WithRaw<T> {
+  type Target = T;
+
+  fn deref(&self) -> &T {
+    &self.value
+  }
+}
+
+impl<T> Serialize for WithRaw<T> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    self.raw.serialize(serializer)
+  }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for WithRaw<T> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let raw = Value::deserialize(deserializer)?;
+    let value = serde_path_to_error::deserialize(&raw)
+      .map_err(|error| D::Error::custom(format!("{}: {}", error.path(), error.inner())))?;
+
+    Ok(WithRaw { value, raw })
+  }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema> schemars::JsonSchema for WithRaw<T> {
+  fn schema_name() -> Cow<'static, str> {
+    T::schema_name()
+  }
+
+  fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    T::json_schema(generator)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_raw_incident_borrows_from_body() {
+    let body = br#"{
+      "incidents": [{
+        "incidentNumber": "42",
+        "currentPhase": "UNACKED",
+        "entityState": "CRITICAL",
+        "entityDisplayName": "disk \"full\"",
+        "startTime": "2026-01-01T00:00:00Z",
+        "pagedTeams": ["team-ops"],
+        "transitions": [{"Name": "ACKED", "By": "jdoe", "At": "2026-01-01T00:05:00Z"}],
+        "pagedPolicies": [{"policy": {"name": "Primary", "slug": "pol-1"}}]
+      }]
+    }"#;
+
+    let response = RawIncidentResponse::from_slice(body).unwrap();
+    let incident = &response.incidents[0];
+
+    assert!(matches!(
+      incident.incident_number,
+      Some(Cow::Borrowed("42"))
+    ));
+    assert!(matches!(incident.paged_teams[0], Cow::Borrowed("team-ops")));
+    assert!(matches!(
+      incident.transitions[0].by,
+      Some(Cow::Borrowed("jdoe"))
+    ));
+    assert!(
+      matches!(&incident.entity_display_name, Some(Cow::Owned(name)) if name == "disk \"full\"")
+    );
+    assert_eq!(incident.severity(), Severity::Critical);
+
+    let owned = incident.to_incident();
+    assert_eq!(owned.incident_number.as_deref(), Some("42"));
+    assert_eq!(owned.acked_by(), Some("jdoe"));
+    assert!(owned.start_time.is_some());
+  }
+
+  #[test]
+  fn test_raw_history_page() {
+    let body = br#"{"offset": 0, "limit": 2, "total": 5, "incidents": [{"incidentNumber": "1"}]}"#;
+    let page = RawIncidentHistoryPage::from_slice(body).unwrap();
+
+    assert_eq!(page.total, Some(5));
+    assert_eq!(page.incidents.len(), 1);
+  }
+
+  #[test]
+  fn test_with_raw_keeps_unmodeled_fields() {
+    let body = br#"[{"incidentNumber": "42", "futureField": {"a": 1}}]"#;
+    let incidents: Vec<WithRaw<Incident>> = serde_json::from_slice(body).unwrap();
+
+    assert_eq!(incidents[0].incident_number.as_deref(), Some("42"));
+    assert_eq!(incidents[0].raw["futureField"], serde_json::json!({"a": 1}));
+    assert_eq!(
+      serde_json::to_string(&incidents).unwrap(),
+      r#"[{"futureField":{"a":1},"incidentNumber":"42"}]"#
+    );
+
+    let error = serde_json::from_str::<WithRaw<Incident>>(r#"{"alertCount": "many"}"#).unwrap_err();
+    assert!(error.to_string().starts_with("alertCount: invalid type"));
+  }
+}
@@ -0,0 +1,309 @@
+use crate::types::*;
+use chrono::{DateTime, Utc};
+use std::ops::Range;
+
+/// Where a resolved on-call interval comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScheduleSource {
+  /// The user is scheduled directly.
+  Scheduled,
+  /// The user is covering for someone through an override.
+  Override,
+  /// The user is on-call through a rotation group roll.
+  Roll,
+}
+
+/// A single on-call interval with overrides already applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedScheduleEntry {
+  /// The username of the on-call user.
+  pub user: String,
+  /// The start of the interval.
+  pub start: DateTime<Utc>,
+  /// The end of the interval.
+  pub end: DateTime<Utc>,
+  /// Where the interval comes from.
+  pub source: ScheduleSource,
+  /// The slug of the escalation policy the interval belongs to.
+  pub policy: Option<String>,
+}
+
+impl ApiEscalationPolicySchedule {
+  /// Resolves the schedule into concrete on-call intervals.
+  ///
+  /// Each roll becomes an interval for its on-call user. Overrides then
+  /// replace the overlapping part of the original user's intervals with an
+  /// interval for the covering user, so the portions they cover no longer
+  /// overlap. Rolls and overrides missing a user or either bound are skipped.
+  ///
+  /// # Returns
+  ///
+  /// The intervals sorted by start time, then by username.
+  pub fn resolve(&self) -> Vec<ResolvedScheduleEntry> {
+    let policy = self.policy.as_ref().and_then(|p| p.slug.clone());
+    let mut entries = Vec::new();
+
+    for entry in &self.schedule {
+      let source = match entry.on_call_type {
+        Some(OnCallType::User) => ScheduleSource::Scheduled,
+        Some(OnCallType::Override) => ScheduleSource::Override,
+        _ => ScheduleSource::Roll,
+      };
+
+      for roll in &entry.rolls {
+        if let (Some(start), Some(end), Some(user)) = (
+          roll.start,
+          roll.end,
+          roll.on_call_user.as_ref().and_then(|u| u.username.clone()),
+        ) {
+          entries.push(ResolvedScheduleEntry {
+            user,
+            start,
+            end,
+            source,
+            policy: policy.clone(),
+          });
+        }
+      }
+    }
+
+    for over in &self.overrides {
+      let (Some(orig), Some(replacement), Some(start), Some(end)) = (
+        over
+          .orig_on_call_user
+          .as_ref()
+          .and_then(|u| u.username.as_deref()),
+        over
+          .override_on_call_user
+          .as_ref()
+          .and_then(|u| u.username.as_deref()),
+        over.start,
+        over.end,
+      ) else {
+        continue;
+      };
+
+      let mut next = Vec::with_capacity(entries.len());
+
+      for entry in entries {
+        let overlap_start = entry.start.max(start);
+        let overlap_end = entry.end.min(end);
+
+        if entry.user != orig || overlap_end <= overlap_start {
+          next.push(entry);
+          continue;
+        }
+
+        if entry.start < overlap_start {
+          next.push(ResolvedScheduleEntry {
+            end: overlap_start,
+            ..entry.clone()
+          });
+        }
+        next.push(ResolvedScheduleEntry {
+          user: replacement.to_string(),
+          start: overlap_start,
+          end: overlap_end,
+          source: ScheduleSource::Override,
+          policy: entry.policy.clone(),
+        });
+        if overlap_end < entry.end {
+          next.push(ResolvedScheduleEntry {
+            start: overlap_end,
+            ..entry
+          });
+        }
+      }
+
+      entries = next;
+    }
+
+    entries.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.user.cmp(&b.user)));
+    entries
+  }
+}
+
+impl ApiTeamSchedule {
+  /// Returns every on-call interval across the team's escalation policies.
+  ///
+  /// Each item is the policy slug, the on-call username, and the covered
+  /// time range, with overrides applied as in
+  /// [`ApiEscalationPolicySchedule::resolve`].
+  ///
+  /// # Returns
+  ///
+  /// An iterator over the intervals in chronological order.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # async fn run(client: victorops::Client) -> victorops::ApiResult<()> {
+  /// let saturday_night: victorops::Timestamp = "2026-01-03T23:00:00Z".parse().unwrap();
+  /// let (schedule, _) = client.get_api_team_schedule("team-ops", 7, 0, 0).await?;
+  ///
+  /// for (policy, user, _) in schedule
+  ///   .intervals()
+  ///   .filter(|(_, _, range)| range.contains(&saturday_night))
+  /// {
+  ///   println!("{} covers {:?}", user, policy);
+  /// }
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn intervals(
+    &self,
+  ) -> impl Iterator<Item = (Option<String>, String, Range<DateTime<Utc>>)> + use<> {
+    let mut entries: Vec<ResolvedScheduleEntry> =
+      self.schedules.iter().flat_map(|s| s.resolve()).collect();
+    entries.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.user.cmp(&b.user)));
+
+    entries
+      .into_iter()
+      .map(|entry| (entry.policy, entry.user, entry.start..entry.end))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::TimeZone;
+
+  fn at(day: u32, hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, day, hour, 0, 0).unwrap()
+  }
+
+  fn user(username: &str) -> Option<ApiUser> {
+    Some(ApiUser::new(username))
+  }
+
+  fn entry(on_call_type: OnCallType, rolls: Vec<(&str, u32, u32)>) -> ApiOnCallEntry {
+    ApiOnCallEntry {
+      on_call_user: None,
+      override_on_call_user: None,
+      on_call_type: Some(on_call_type),
+      rotation_name: None,
+      shift_name: None,
+      shift_roll: None,
+      rolls: rolls
+        .into_iter()
+        .map(|(username, start, end)| ApiOnCallRoll {
+          start: Some(at(start, 0)),
+          end: Some(at(end, 0)),
+          on_call_user: user(username),
+          is_roll: Some(true),
+        })
+        .collect(),
+    }
+  }
+
+  #[test]
+  fn test_resolve_splits_rolls_around_overrides() {
+    let schedule = ApiEscalationPolicySchedule {
+      policy: Some(ApiEscalationPolicy {
+        name: Some("Primary".to_string()),
+        slug: Some("pol-primary".to_string()),
+      }),
+      schedule: vec![
+        entry(OnCallType::Rotation, vec![("alice", 1, 4)]),
+        entry(OnCallType::User, vec![("carol", 1, 2)]),
+      ],
+      overrides: vec![ApiOnCallOverride {
+        orig_on_call_user: user("alice"),
+        override_on_call_user: user("bob"),
+        start: Some(at(2, 0)),
+        end: Some(at(3, 0)),
+        policy: None,
+      }],
+    };
+
+    let resolved: Vec<_> = schedule
+      .resolve()
+      .into_iter()
+      .map(|e| (e.user, e.start, e.end, e.source))
+      .collect();
+
+    assert_eq!(
+      resolved,
+      vec![
+        (
+          "alice".to_string(),
+          at(1, 0),
+          at(2, 0),
+          ScheduleSource::Roll
+        ),
+        (
+          "carol".to_string(),
+          at(1, 0),
+          at(2, 0),
+          ScheduleSource::Scheduled
+        ),
+        (
+          "bob".to_string(),
+          at(2, 0),
+          at(3, 0),
+          ScheduleSource::Override
+        ),
+        (
+          "alice".to_string(),
+          at(3, 0),
+          at(4, 0),
+          ScheduleSource::Roll
+        ),
+      ]
+    );
+    assert!(
+      schedule
+        .resolve()
+        .iter()
+        .all(|e| e.policy.as_deref() == Some("pol-primary"))
+    );
+  }
+
+  #[test]
+  fn test_team_intervals_are_chronological_across_policies() {
+    let policy = |slug: &str, rolls| ApiEscalationPolicySchedule {
+      policy: Some(ApiEscalationPolicy {
+        name: None,
+        slug: Some(slug.to_string()),
+      }),
+      schedule: vec![entry(OnCallType::Rotation, rolls)],
+      overrides: vec![],
+    };
+    let schedule = ApiTeamSchedule {
+      team: None,
+      schedules: vec![
+        policy("pol-primary", vec![("alice", 1, 2), ("bob", 3, 4)]),
+        policy("pol-secondary", vec![("carol", 2, 3)]),
+      ],
+    };
+
+    let intervals: Vec<_> = schedule.intervals().collect();
+    assert_eq!(
+      intervals,
+      vec![
+        (
+          Some("pol-primary".to_string()),
+          "alice".to_string(),
+          at(1, 0)..at(2, 0)
+        ),
+        (
+          Some("pol-secondary".to_string()),
+          "carol".to_string(),
+          at(2, 0)..at(3, 0)
+        ),
+        (
+          Some("pol-primary".to_string()),
+          "bob".to_string(),
+          at(3, 0)..at(4, 0)
+        ),
+      ]
+    );
+
+    let covering: Vec<_> = schedule
+      .intervals()
+      .filter(|(_, _, range)| range.contains(&at(2, 12)))
+      .map(|(_, user, _)| user)
+      .collect();
+    assert_eq!(covering, ["carol"]);
+  }
+}
@@ -0,0 +1,59 @@
+use crate::error::ApiResult;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Record and replay of real API interactions for testing.
+#[cfg(feature = "test-util")]
+pub mod cassette;
+
+/// Scripted transport for testing code built on this crate.
+#[cfg(feature = "test-util")]
+pub mod mock;
+
+/// A boxed future returned by [`Transport::execute`].
+pub type TransportFuture<'a> =
+  Pin<Box<dyn Future<Output = ApiResult<reqwest::Response>> + Send + 'a>>;
+
+/// Sends fully built HTTP requests on behalf of a [`Client`](crate::Client).
+///
+/// The client builds each request, including credentials and default
+/// headers, and hands it to its transport. The default transport sends it
+/// with `reqwest`; a custom transport can route requests elsewhere or, with
+/// the `test-util` feature, answer them from a script with
+/// [`MockTransport`](mock::MockTransport).
+pub trait Transport: fmt::Debug + Send + Sync {
+  /// Sends a request and returns its response.
+  fn execute(&self, request: reqwest::Request) -> TransportFuture<'_>;
+}
+
+/// The default transport, which sends requests over the network with `reqwest`.
+#[derive(Clone)]
+pub struct ReqwestTransport {
+  http_client: reqwest::Client,
+}
+
+impl fmt::Debug for ReqwestTransport {
+  // The reqwest client's own Debug output includes proxy credentials and
+  // default header values.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ReqwestTransport").finish_non_exhaustive()
+  }
+}
+
+impl ReqwestTransport {
+  /// Creates a transport that sends requests with the given `reqwest` client.
+  ///
+  /// # Arguments
+  ///
+  /// * `http_client` - The configured `reqwest` client
+  pub fn new(http_client: reqwest::Client) -> Self {
+    ReqwestTransport { http_client }
+  }
+}
+
+impl Transport for ReqwestTransport {
+  fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+    Box::pin(async move { Ok(self.http_client.execute(request).await?) })
+  }
+}
@@ -0,0 +1,435 @@
+//! Record real API interactions to a cassette file and replay them in tests.
+//!
+//! Record once against the live API with a [`RecordingTransport`], then
+//! commit the cassette and replay it with a [`ReplayTransport`] so tests run
+//! without credentials or a network. Request headers are never recorded, and
+//! any secrets registered with [`RecordingTransport::redact`] are scrubbed
+//! from URLs and bodies before the cassette is written.
+
+use super::{Transport, TransportFuture};
+use crate::error::{ApiResult, Error};
+use bytes::Bytes;
+use reqwest::header::{HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// The placeholder written in place of redacted secrets.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Response headers that are never recorded.
+const SENSITIVE_RESPONSE_HEADERS: &[&str] = &["set-cookie", "authorization"];
+
+/// A recorded request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RecordedRequest {
+  /// The HTTP method.
+  pub method: String,
+  /// The URL path and query string, such as `/api-public/v1/team`.
+  pub path: String,
+  /// The request body, if one was sent.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub body: Option<String>,
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RecordedResponse {
+  /// The HTTP status code.
+  pub status: u16,
+  /// The response headers.
+  #[serde(default)]
+  pub headers: BTreeMap<String, String>,
+  /// The response body.
+  #[serde(default)]
+  pub body: String,
+}
+
+/// A single recorded request and the response it received.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Interaction {
+  /// The request that was sent.
+  pub request: RecordedRequest,
+  /// The response that was received.
+  pub response: RecordedResponse,
+}
+
+/// A sequence of recorded interactions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Cassette {
+  /// The interactions, in the order they were recorded.
+  pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+  /// Reads a cassette from a JSON file.
+  ///
+  /// # Arguments
+  ///
+  /// * `path` - The cassette file
+  pub fn load(path: impl AsRef<Path>) -> ApiResult<Self> {
+    let contents = std::fs::read(path)?;
+    Ok(serde_json::from_slice(&contents)?)
+  }
+
+  /// Writes the cassette to a pretty-printed JSON file.
+  ///
+  /// # Arguments
+  ///
+  /// * `path` - The cassette file
+  pub fn save(&self, path: impl AsRef<Path>) -> ApiResult<()> {
+    std::fs::write(path, serde_json::to_vec_pretty(self)?)?;
+    Ok(())
+  }
+}
+
+/// A transport that forwards requests to another transport and records them.
+///
+/// # Examples
+///
+/// ```no_run
+/// use victorops::transport::ReqwestTransport;
+/// use victorops::transport::cassette::RecordingTransport;
+///
+/// # #[tokio::main]
+/// # async fn main() -> victorops::ApiResult<()> {
+/// let recorder = RecordingTransport::new(ReqwestTransport::new(reqwest::Client::new()))
+///   .redact("my-api-id")
+///   .redact("my-api-key");
+///
+/// let client = victorops::Client::builder("my-api-id", "my-api-key", "https://api.victorops.com")
+///   .transport(recorder.clone())
+///   .build()?;
+/// client.get_all_teams().await?;
+///
+/// recorder.save("tests/cassettes/teams.json")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RecordingTransport {
+  inner: Arc<dyn Transport>,
+  secrets: Vec<String>,
+  cassette: Arc<Mutex<Cassette>>,
+}
+
+impl RecordingTransport {
+  /// Creates a recorder that sends requests with `inner`.
+  ///
+  /// # Arguments
+  ///
+  /// * `inner` - The transport that performs the real requests
+  pub fn new(inner: impl Transport + 'static) -> Self {
+    RecordingTransport {
+      inner: Arc::new(inner),
+      secrets: Vec::new(),
+      cassette: Arc::new(Mutex::new(Cassette::default())),
+    }
+  }
+
+  /// Scrubs a secret from recorded URLs, bodies, and response headers.
+  ///
+  /// # Arguments
+  ///
+  /// * `secret` - A value that must not appear in the cassette
+  pub fn redact(mut self, secret: impl Into<String>) -> Self {
+    let secret = secret.into();
+    if !secret.is_empty() {
+      self.secrets.push(secret);
+    }
+    self
+  }
+
+  /// Returns a copy of the interactions recorded so far.
+  pub fn cassette(&self) -> Cassette {
+    self
+      .cassette
+      .lock()
+      .unwrap_or_else(|e| e.into_inner())
+      .clone()
+  }
+
+  /// Writes the recorded interactions to a cassette file.
+  ///
+  /// # Arguments
+  ///
+  /// * `path` - The cassette file
+  pub fn save(&self, path: impl AsRef<Path>) -> ApiResult<()> {
+    self.cassette().save(path)
+  }
+
+  fn scrub(&self, text: &str) -> String {
+    self.secrets.iter().fold(text.to_string(), |text, secret| {
+      text.replace(secret, REDACTED)
+    })
+  }
+}
+
+impl fmt::Debug for RecordingTransport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    // The inner transport may hold requests with credentials.
+    f.debug_struct("RecordingTransport")
+      .field("secrets", &self.secrets.len())
+      .finish_non_exhaustive()
+  }
+}
+
+impl Transport for RecordingTransport {
+  fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+    let recorded_request = RecordedRequest {
+      method: request.method().to_string(),
+      path: self.scrub(&path_and_query(request.url())),
+      body: request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(|body| self.scrub(&String::from_utf8_lossy(body))),
+    };
+
+    Box::pin(async move {
+      let response = self.inner.execute(request).await?;
+      let status = response.status().as_u16();
+      let headers = response.headers().clone();
+      let body = response.bytes().await?;
+
+      let recorded_response = RecordedResponse {
+        status,
+        headers: headers
+          .iter()
+          .filter(|(name, _)| !SENSITIVE_RESPONSE_HEADERS.contains(&name.as_str()))
+          .filter_map(|(name, value)| Some((name.to_string(), self.scrub(value.to_str().ok()?))))
+          .collect(),
+        body: self.scrub(&String::from_utf8_lossy(&body)),
+      };
+
+      self
+        .cassette
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .interactions
+        .push(Interaction {
+          request: recorded_request,
+          response: recorded_response,
+        });
+
+      let mut rebuilt = http::Response::new(body);
+      *rebuilt.status_mut() = http::StatusCode::from_u16(status).expect("status was valid");
+      *rebuilt.headers_mut() = headers;
+      Ok(reqwest::Response::from(rebuilt))
+    })
+  }
+}
+
+/// A transport that answers requests from a recorded cassette.
+///
+/// A request is answered by the first unused interaction with the same
+/// method, path and query, and body. Secrets that were redacted while
+/// recording are matched against [`REDACTED`], so replay works with any
+/// credentials. Unmatched requests fail with `Error::InvalidInput`.
+#[derive(Clone)]
+pub struct ReplayTransport {
+  secrets: Vec<String>,
+  remaining: Arc<Mutex<Vec<Interaction>>>,
+}
+
+impl ReplayTransport {
+  /// Creates a transport that replays a cassette.
+  ///
+  /// # Arguments
+  ///
+  /// * `cassette` - The recorded interactions
+  pub fn new(cassette: Cassette) -> Self {
+    ReplayTransport {
+      secrets: Vec::new(),
+      remaining: Arc::new(Mutex::new(cassette.interactions)),
+    }
+  }
+
+  /// Creates a transport that replays a cassette file.
+  ///
+  /// # Arguments
+  ///
+  /// * `path` - The cassette file
+  pub fn from_file(path: impl AsRef<Path>) -> ApiResult<Self> {
+    Ok(Self::new(Cassette::load(path)?))
+  }
+
+  /// Treats a secret in replayed requests as the redaction placeholder.
+  ///
+  /// # Arguments
+  ///
+  /// * `secret` - A value that was redacted when recording
+  pub fn redact(mut self, secret: impl Into<String>) -> Self {
+    let secret = secret.into();
+    if !secret.is_empty() {
+      self.secrets.push(secret);
+    }
+    self
+  }
+
+  /// Returns the number of recorded interactions not yet replayed.
+  pub fn remaining(&self) -> usize {
+    self
+      .remaining
+      .lock()
+      .unwrap_or_else(|e| e.into_inner())
+      .len()
+  }
+
+  fn scrub(&self, text: &str) -> String {
+    self.secrets.iter().fold(text.to_string(), |text, secret| {
+      text.replace(secret, REDACTED)
+    })
+  }
+}
+
+impl fmt::Debug for ReplayTransport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ReplayTransport")
+      .field("secrets", &self.secrets.len())
+      .field("remaining", &self.remaining())
+      .finish()
+  }
+}
+
+impl Transport for ReplayTransport {
+  fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+    let wanted = RecordedRequest {
+      method: request.method().to_string(),
+      path: self.scrub(&path_and_query(request.url())),
+      body: request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(|body| self.scrub(&String::from_utf8_lossy(body))),
+    };
+
+    let interaction = {
+      let mut remaining = self.remaining.lock().unwrap_or_else(|e| e.into_inner());
+      remaining
+        .iter()
+        .position(|interaction| interaction.request == wanted)
+        .map(|index| remaining.remove(index))
+    };
+
+    Box::pin(async move {
+      let Some(interaction) = interaction else {
+        return Err(Error::InvalidInput(format!(
+          "no recorded interaction for {} {}",
+          wanted.method, wanted.path
+        )));
+      };
+
+      let recorded = interaction.response;
+      let mut response = http::Response::new(Bytes::from(recorded.body));
+      *response.status_mut() = http::StatusCode::from_u16(recorded.status)
+        .map_err(|_| Error::InvalidInput(format!("invalid recorded status {}", recorded.status)))?;
+      for (name, value) in &recorded.headers {
+        let name = HeaderName::from_bytes(name.as_bytes())
+          .map_err(|_| Error::InvalidInput(format!("invalid recorded header {:?}", name)))?;
+        response
+          .headers_mut()
+          .append(name, HeaderValue::from_str(value)?);
+      }
+
+      Ok(reqwest::Response::from(response))
+    })
+  }
+}
+
+fn path_and_query(url: &reqwest::Url) -> String {
+  match url.query() {
+    Some(query) => format!("{}?{}", url.path(), query),
+    None => url.path().to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::transport::mock::{MockResponse, MockTransport};
+  use crate::{Client, Transport};
+  use serde_json::json;
+
+  fn client(transport: impl Transport + 'static, api_key: &str) -> Client {
+    Client::builder("test-api-id", api_key, "https://api.victorops.com")
+      .transport(transport)
+      .build()
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_record_then_replay() {
+    let live = MockTransport::new();
+    live
+      .push(
+        MockResponse::json(200, &json!([{"name": "Ops", "slug": "team-ops"}]))
+          .header("set-cookie", "session=abc"),
+      )
+      .push(MockResponse::json(
+        200,
+        &json!({"name": "Db", "slug": "team-db", "token": "live-api-key"}),
+      ));
+
+    let recorder = RecordingTransport::new(live).redact("live-api-key");
+    let recording_client = client(recorder.clone(), "live-api-key");
+    recording_client.get_all_teams().await.unwrap();
+    let team = serde_json::from_value(json!({"name": "Db"})).unwrap();
+    recording_client.create_team(&team).await.unwrap();
+
+    let dir = std::env::temp_dir().join(format!("victorops-cassette-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("teams.json");
+    recorder.save(&path).unwrap();
+
+    assert!(!format!("{:?}", recorder).contains("live-api-key"));
+
+    let saved = std::fs::read_to_string(&path).unwrap();
+    assert!(!saved.contains("live-api-key"));
+    assert!(!saved.contains("session=abc"));
+    assert!(saved.contains(REDACTED));
+
+    let replay = ReplayTransport::from_file(&path).unwrap();
+    let replay_client = client(replay.clone(), "other-api-key");
+
+    let (created, _) = replay_client.create_team(&team).await.unwrap();
+    assert_eq!(created.slug, Some("team-db".to_string()));
+    let (teams, _) = replay_client.get_all_teams().await.unwrap();
+    assert_eq!(teams[0].slug, Some("team-ops".to_string()));
+    assert_eq!(replay.remaining(), 0);
+
+    let unmatched = replay_client.get_team("team-ops").await;
+    assert!(matches!(unmatched, Err(Error::InvalidInput(_))));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_replay_matches_redacted_secrets_in_path() {
+    let cassette = Cassette {
+      interactions: vec![Interaction {
+        request: RecordedRequest {
+          method: "GET".to_string(),
+          path: format!("/api-public/v1/user/{}", REDACTED),
+          body: None,
+        },
+        response: RecordedResponse {
+          status: 200,
+          headers: BTreeMap::new(),
+          body: r#"{"username": "svc"}"#.to_string(),
+        },
+      }],
+    };
+
+    let replay = ReplayTransport::new(cassette).redact("svc-account");
+    let (user, _) = client(replay, "test-api-key")
+      .get_user("svc-account")
+      .await
+      .unwrap();
+    assert_eq!(user.username, Some("svc".to_string()));
+  }
+}
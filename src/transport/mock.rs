@@ -0,0 +1,276 @@
+//! A scripted [`Transport`] for unit-testing code built on [`Client`](crate::Client)
+//! without a network or a mock HTTP server.
+//!
+//! Queue canned responses, build a client with
+//! [`ClientBuilder::transport`](crate::ClientBuilder::transport), then assert
+//! on the requests the client sent.
+
+use super::{Transport, TransportFuture};
+use crate::error::{ApiResult, Error};
+use bytes::Bytes;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// A canned response returned by a [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+  status: u16,
+  headers: HeaderMap,
+  body: Bytes,
+}
+
+impl MockResponse {
+  /// Creates an empty response with the given status code.
+  ///
+  /// # Arguments
+  ///
+  /// * `status` - The HTTP status code
+  pub fn new(status: u16) -> Self {
+    MockResponse {
+      status,
+      headers: HeaderMap::new(),
+      body: Bytes::new(),
+    }
+  }
+
+  /// Creates a response with a JSON body.
+  ///
+  /// # Arguments
+  ///
+  /// * `status` - The HTTP status code
+  /// * `body` - The value to serialize as the response body
+  pub fn json<T: Serialize + ?Sized>(status: u16, body: &T) -> Self {
+    MockResponse::new(status)
+      .header("content-type", "application/json")
+      .body(serde_json::to_vec(body).expect("mock response body must serialize"))
+  }
+
+  /// Adds a response header.
+  ///
+  /// # Arguments
+  ///
+  /// * `name` - The header name
+  /// * `value` - The header value
+  pub fn header(mut self, name: &str, value: &str) -> Self {
+    self.headers.append(
+      HeaderName::from_bytes(name.as_bytes()).expect("mock header name must be valid"),
+      HeaderValue::from_str(value).expect("mock header value must be valid"),
+    );
+    self
+  }
+
+  /// Sets the raw response body.
+  ///
+  /// # Arguments
+  ///
+  /// * `body` - The response body
+  pub fn body(mut self, body: impl Into<Bytes>) -> Self {
+    self.body = body.into();
+    self
+  }
+
+  fn into_response(self) -> reqwest::Response {
+    let mut response = http::Response::new(self.body);
+    *response.status_mut() =
+      http::StatusCode::from_u16(self.status).expect("mock status code must be valid");
+    *response.headers_mut() = self.headers;
+    reqwest::Response::from(response)
+  }
+}
+
+/// A request received by a [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+  /// The HTTP method.
+  pub method: reqwest::Method,
+  /// The full request URL, including the query string.
+  pub url: String,
+  /// The request headers, including credentials.
+  pub headers: HeaderMap,
+  /// The request body.
+  pub body: Bytes,
+}
+
+impl RecordedRequest {
+  /// Returns the URL path, such as `/api-public/v1/team`.
+  pub fn path(&self) -> String {
+    url::Url::parse(&self.url)
+      .map(|url| url.path().to_string())
+      .unwrap_or_default()
+  }
+
+  /// Parses the request body as JSON.
+  pub fn json<T: serde::de::DeserializeOwned>(&self) -> ApiResult<T> {
+    Ok(serde_json::from_slice(&self.body)?)
+  }
+}
+
+#[derive(Debug, Default)]
+struct Script {
+  responses: VecDeque<MockResponse>,
+  requests: Vec<RecordedRequest>,
+}
+
+/// A transport that answers requests from a queue of canned responses.
+///
+/// Responses are returned in the order they were queued, whatever the
+/// request. A request made after the queue is exhausted fails with
+/// `Error::InvalidInput`. Clones share the same script, so keep a clone to
+/// inspect requests after handing one to the client.
+///
+/// # Examples
+///
+/// ```
+/// use victorops::transport::mock::{MockResponse, MockTransport};
+///
+/// # #[tokio::main]
+/// # async fn main() -> victorops::ApiResult<()> {
+/// let transport = MockTransport::new();
+/// transport.push(MockResponse::json(200, &serde_json::json!([{"slug": "team-ops"}])));
+///
+/// let client = victorops::Client::builder("api-id", "api-key", "https://api.victorops.com")
+///   .transport(transport.clone())
+///   .build()?;
+///
+/// let (teams, _) = client.get_all_teams().await?;
+/// assert_eq!(teams[0].slug.as_deref(), Some("team-ops"));
+/// assert_eq!(transport.requests()[0].path(), "/api-public/v1/team");
+/// transport.assert_exhausted();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+  script: Arc<Mutex<Script>>,
+}
+
+impl MockTransport {
+  /// Creates a transport with no queued responses.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Queues a response for the next unanswered request.
+  ///
+  /// # Arguments
+  ///
+  /// * `response` - The response to return
+  pub fn push(&self, response: MockResponse) -> &Self {
+    self.script().responses.push_back(response);
+    self
+  }
+
+  /// Returns every request received so far, in order.
+  pub fn requests(&self) -> Vec<RecordedRequest> {
+    self.script().requests.clone()
+  }
+
+  /// Returns the number of queued responses not yet returned.
+  pub fn remaining(&self) -> usize {
+    self.script().responses.len()
+  }
+
+  /// Panics unless every queued response has been returned.
+  pub fn assert_exhausted(&self) {
+    let remaining = self.remaining();
+    assert!(
+      remaining == 0,
+      "{} queued mock response(s) were never requested",
+      remaining
+    );
+  }
+
+  fn script(&self) -> std::sync::MutexGuard<'_, Script> {
+    self.script.lock().unwrap_or_else(|e| e.into_inner())
+  }
+}
+
+impl Transport for MockTransport {
+  fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+    let recorded = RecordedRequest {
+      method: request.method().clone(),
+      url: request.url().to_string(),
+      headers: request.headers().clone(),
+      body: request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(Bytes::copy_from_slice)
+        .unwrap_or_default(),
+    };
+
+    let mut script = self.script();
+    let response = script.responses.pop_front();
+    let description = format!("{} {}", recorded.method, recorded.url);
+    script.requests.push(recorded);
+    drop(script);
+
+    Box::pin(async move {
+      response
+        .map(MockResponse::into_response)
+        .ok_or_else(|| Error::InvalidInput(format!("no mock response queued for {}", description)))
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Client, Team};
+  use serde_json::json;
+
+  fn client(transport: &MockTransport) -> Client {
+    Client::builder("test-api-id", "test-api-key", "https://api.victorops.com")
+      .transport(transport.clone())
+      .build()
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_scripted_responses_and_recorded_requests() {
+    let transport = MockTransport::new();
+    transport
+      .push(MockResponse::json(
+        200,
+        &json!({"name": "Ops", "slug": "team-ops"}),
+      ))
+      .push(MockResponse::new(404).body("Team not found"));
+
+    let client = client(&transport);
+    let (created, _) = client.create_team(&Team::new("Ops")).await.unwrap();
+    assert_eq!(created.slug, Some("team-ops".to_string()));
+
+    let missing = client.get_team("team-db").await;
+    assert!(matches!(missing, Err(Error::NotFound { .. })));
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, reqwest::Method::POST);
+    assert_eq!(requests[0].path(), "/api-public/v1/team");
+    assert_eq!(requests[0].headers["x-vo-api-id"], "test-api-id");
+    assert_eq!(
+      requests[0].json::<serde_json::Value>().unwrap(),
+      json!({"name": "Ops"})
+    );
+    assert_eq!(requests[1].path(), "/api-public/v1/team/team-db");
+    transport.assert_exhausted();
+  }
+
+  #[tokio::test]
+  async fn test_exhausted_script_fails_request() {
+    let transport = MockTransport::new();
+    let result = client(&transport).get_all_teams().await;
+    assert!(matches!(result, Err(Error::InvalidInput(_))));
+    assert_eq!(transport.requests().len(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_response_headers_reach_request_details() {
+    let transport = MockTransport::new();
+    transport.push(MockResponse::json(200, &json!([])).header("etag", "\"v1\""));
+
+    let (_, details) = client(&transport).get_all_teams().await.unwrap();
+    assert_eq!(details.etag.as_deref(), Some("\"v1\""));
+  }
+}
@@ -1,19 +1,135 @@
+use crate::error::{ApiResult, Error};
+use crate::validate::{Validate, routing_key_problem};
+use bytes::Bytes;
+#[cfg(feature = "chrono")]
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// A point in time sent to or returned by the VictorOps API.
+///
+/// With the default `chrono` feature this is a `chrono::DateTime<Utc>`.
+/// Without it, timestamps are kept as the RFC 3339 strings the API sends.
+#[cfg(feature = "chrono")]
+pub type Timestamp = DateTime<Utc>;
+
+/// A point in time sent to or returned by the VictorOps API.
+///
+/// With the default `chrono` feature this is a `chrono::DateTime<Utc>`.
+/// Without it, timestamps are kept as the RFC 3339 strings the API sends.
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
+/// Implements `iter()` and `IntoIterator` for a response that wraps a list.
+macro_rules! impl_list_response {
+  ($list:ty, $field:ident, $item:ty) => {
+    impl $list {
+      /// Returns an iterator over the items in the response.
+      pub fn iter(&self) -> std::slice::Iter<'_, $item> {
+        self.$field.iter()
+      }
+    }
+
+    impl IntoIterator for $list {
+      type Item = $item;
+      type IntoIter = std::vec::IntoIter<$item>;
+
+      fn into_iter(self) -> Self::IntoIter {
+        self.$field.into_iter()
+      }
+    }
+
+    impl<'a> IntoIterator for &'a $list {
+      type Item = &'a $item;
+      type IntoIter = std::slice::Iter<'a, $item>;
+
+      fn into_iter(self) -> Self::IntoIter {
+        self.$field.iter()
+      }
+    }
+  };
+}
 
 /// Details about an HTTP request made to the VictorOps API.
-#[derive(Debug, Clone)]
+///
+/// Credentials are redacted from the recorded URL and request headers, so
+/// details can be logged when debugging a failed call.
+#[derive(Debug, Clone, Default)]
 pub struct RequestDetails {
   /// The HTTP status code of the response.
   pub status_code: u16,
-  /// The response body as a string.
-  pub response_body: String,
+  /// The raw response body.
+  pub response_bytes: Bytes,
   /// The request body that was sent.
   pub request_body: String,
+  /// The `ETag` response header, if the server sent one.
+  pub etag: Option<String>,
+  /// The `Last-Modified` response header, if the server sent one.
+  pub last_modified: Option<String>,
+  /// The HTTP method of the request.
+  pub method: reqwest::Method,
+  /// The full request URL, including the query string.
+  pub url: String,
+  /// The request headers that were sent, with credentials redacted.
+  pub request_headers: reqwest::header::HeaderMap,
+  /// The response headers.
+  pub response_headers: reqwest::header::HeaderMap,
+  /// The time from sending the first attempt to reading the final response,
+  /// including any rate-limit retries.
+  pub elapsed: std::time::Duration,
+  /// Values dropped from the response by lenient parsing, as `path: problem`.
+  ///
+  /// Always empty unless the client was configured with
+  /// `Client::with_lenient_parsing`.
+  pub parse_warnings: Vec<String>,
+}
+
+impl RequestDetails {
+  /// Returns the response body as text, replacing any invalid UTF-8.
+  ///
+  /// The text is only decoded when this is called; endpoint methods parse
+  /// [`RequestDetails::response_bytes`] directly.
+  pub fn response_body(&self) -> Cow<'_, str> {
+    String::from_utf8_lossy(&self.response_bytes)
+  }
+
+  /// Returns true if the server reported the resource as unchanged.
+  pub fn is_not_modified(&self) -> bool {
+    self.status_code == 304
+  }
+}
+
+/// The result of a conditional request made with validators from an earlier response.
+#[derive(Debug, Clone)]
+pub enum Conditional<T> {
+  /// The resource changed; carries the new value and its request details.
+  Modified(T, RequestDetails),
+  /// The resource is unchanged since the earlier response.
+  NotModified(RequestDetails),
+}
+
+impl<T> Conditional<T> {
+  /// Returns the request details of the conditional request.
+  pub fn details(&self) -> &RequestDetails {
+    match self {
+      Conditional::Modified(_, details) | Conditional::NotModified(details) => details,
+    }
+  }
+
+  /// Returns the new value, or `None` if the resource was unchanged.
+  pub fn modified(self) -> Option<T> {
+    match self {
+      Conditional::Modified(value, _) => Some(value),
+      Conditional::NotModified(_) => None,
+    }
+  }
 }
 
 /// A paged entity containing basic name and slug information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct PagedEntity {
   /// The name of the entity.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -25,6 +141,8 @@ pub struct PagedEntity {
 
 /// A paged policy containing policy and team information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct PagedPolicy {
   /// The policy entity information.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -36,13 +154,15 @@ pub struct PagedPolicy {
 
 /// Represents a state transition in an incident.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Transition {
   /// The name of the transition.
   #[serde(skip_serializing_if = "Option::is_none", rename = "Name")]
   pub name: Option<String>,
   /// When the transition occurred.
   #[serde(skip_serializing_if = "Option::is_none", rename = "At")]
-  pub at: Option<DateTime<Utc>>,
+  pub at: Option<Timestamp>,
   /// Message associated with the transition.
   #[serde(skip_serializing_if = "Option::is_none", rename = "Message")]
   pub message: Option<String>,
@@ -62,6 +182,8 @@ pub struct Transition {
 
 /// Represents an incident in VictorOps.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Incident {
   /// The number of alerts in this incident.
   #[serde(skip_serializing_if = "Option::is_none", rename = "alertCount")]
@@ -92,13 +214,13 @@ pub struct Incident {
   pub last_alert_id: Option<String>,
   /// The timestamp of the last alert in this incident.
   #[serde(skip_serializing_if = "Option::is_none", rename = "lastAlertTime")]
-  pub last_alert_time: Option<DateTime<Utc>>,
+  pub last_alert_time: Option<Timestamp>,
   /// The service associated with the incident.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub service: Option<String>,
   /// The timestamp when the incident started.
   #[serde(skip_serializing_if = "Option::is_none", rename = "startTime")]
-  pub start_time: Option<DateTime<Utc>>,
+  pub start_time: Option<Timestamp>,
   /// The list of teams that were paged for this incident.
   #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "pagedTeams")]
   pub paged_teams: Vec<String>,
@@ -117,16 +239,584 @@ pub struct Incident {
   pub transitions: Vec<Transition>,
 }
 
+/// Severity classification of an incident or alert.
+///
+/// Variants are ordered from most to least severe, so sorting ascending puts
+/// critical incidents first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+  /// Critical state requiring immediate attention.
+  Critical,
+  /// Warning state that may require attention.
+  Warning,
+  /// Informational state, including acknowledgements and recoveries.
+  Info,
+  /// State that could not be classified.
+  Unknown,
+}
+
+impl Severity {
+  /// Classifies an entity state or alert message type.
+  ///
+  /// Matching is case-insensitive. `CRITICAL` maps to [`Severity::Critical`],
+  /// `WARNING` to [`Severity::Warning`], and `INFO`, `OK`, `ACKNOWLEDGEMENT`,
+  /// and `RECOVERY` to [`Severity::Info`]. Anything else is
+  /// [`Severity::Unknown`].
+  pub fn from_state(state: &str) -> Self {
+    match state.trim().to_ascii_uppercase().as_str() {
+      "CRITICAL" => Severity::Critical,
+      "WARNING" => Severity::Warning,
+      "INFO" | "OK" | "ACKNOWLEDGEMENT" | "RECOVERY" => Severity::Info,
+      _ => Severity::Unknown,
+    }
+  }
+
+  /// Returns the default priority label for this severity.
+  ///
+  /// `Critical` is `P1`, `Warning` is `P2`, `Info` is `P3`, and `Unknown` is
+  /// `P4`. Use a [`SeverityTable`] to change the labels.
+  pub fn priority(&self) -> &'static str {
+    match self {
+      Severity::Critical => "P1",
+      Severity::Warning => "P2",
+      Severity::Info => "P3",
+      Severity::Unknown => "P4",
+    }
+  }
+}
+
+/// A configurable mapping from entity states to severities and priority labels.
+///
+/// States without an entry fall back to [`Severity::from_state`], and
+/// severities without a label fall back to [`Severity::priority`].
+///
+/// # Examples
+///
+/// ```
+/// use victorops::{Severity, SeverityTable};
+///
+/// let table = SeverityTable::new()
+///   .state("DEGRADED", Severity::Warning)
+///   .priority(Severity::Critical, "SEV1");
+///
+/// assert_eq!(table.severity_of("degraded"), Severity::Warning);
+/// assert_eq!(table.priority_of(Severity::Critical), "SEV1");
+/// assert_eq!(table.priority_of(Severity::Warning), "P2");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SeverityTable {
+  states: BTreeMap<String, Severity>,
+  priorities: BTreeMap<Severity, String>,
+}
+
+impl SeverityTable {
+  /// Creates a table that uses the default mappings.
+  pub fn new() -> Self {
+    SeverityTable::default()
+  }
+
+  /// Maps an entity state to a severity.
+  ///
+  /// # Arguments
+  ///
+  /// * `state` - The entity state, matched case-insensitively
+  /// * `severity` - The severity incidents in that state are given
+  pub fn state(mut self, state: &str, severity: Severity) -> Self {
+    self
+      .states
+      .insert(state.trim().to_ascii_uppercase(), severity);
+    self
+  }
+
+  /// Sets the priority label for a severity.
+  ///
+  /// # Arguments
+  ///
+  /// * `severity` - The severity to label
+  /// * `label` - The label, such as `P1` or `SEV1`
+  pub fn priority(mut self, severity: Severity, label: impl Into<String>) -> Self {
+    self.priorities.insert(severity, label.into());
+    self
+  }
+
+  /// Classifies an entity state.
+  pub fn severity_of(&self, state: &str) -> Severity {
+    self
+      .states
+      .get(&state.trim().to_ascii_uppercase())
+      .copied()
+      .unwrap_or_else(|| Severity::from_state(state))
+  }
+
+  /// Returns the priority label for a severity.
+  pub fn priority_of(&self, severity: Severity) -> &str {
+    self
+      .priorities
+      .get(&severity)
+      .map(String::as_str)
+      .unwrap_or_else(|| severity.priority())
+  }
+
+  /// Classifies an incident from its entity state.
+  pub fn classify(&self, incident: &Incident) -> Severity {
+    incident
+      .entity_state
+      .as_deref()
+      .map(|state| self.severity_of(state))
+      .unwrap_or(Severity::Unknown)
+  }
+
+  /// Returns the priority label for an incident.
+  pub fn incident_priority(&self, incident: &Incident) -> &str {
+    self.priority_of(self.classify(incident))
+  }
+}
+
+impl Incident {
+  /// Classifies the incident's severity from its entity state.
+  pub fn severity(&self) -> Severity {
+    self
+      .entity_state
+      .as_deref()
+      .map(Severity::from_state)
+      .unwrap_or(Severity::Unknown)
+  }
+
+  /// Returns the incident number as an integer, for the ack and resolve endpoints.
+  ///
+  /// Surrounding whitespace and a leading `#` are ignored.
+  ///
+  /// # Returns
+  ///
+  /// The number, or `None` if the incident has no number or it is not an integer.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # async fn run(client: victorops::Client) -> victorops::ApiResult<()> {
+  /// let (incidents, _) = client.get_incidents().await?;
+  /// if let Some(number) = incidents.oldest_unacked().and_then(|i| i.incident_number_i64()) {
+  ///   client.ack_incidents("jdoe", &[number], Some("Looking")).await?;
+  /// }
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn incident_number_i64(&self) -> Option<i64> {
+    let number = self.incident_number.as_deref()?.trim();
+    number.strip_prefix('#').unwrap_or(number).parse().ok()
+  }
+
+  /// Returns the first transition with the given name, such as `ACKED`.
+  fn transition(&self, name: &str) -> Option<&Transition> {
+    self.transitions.iter().find(|t| {
+      t.name
+        .as_deref()
+        .is_some_and(|n| n.eq_ignore_ascii_case(name))
+    })
+  }
+
+  /// Returns who acknowledged the incident, if it has been acknowledged.
+  pub fn acked_by(&self) -> Option<&str> {
+    self.transition("ACKED").and_then(|t| t.by.as_deref())
+  }
+
+  /// Returns who resolved the incident, if it has been resolved.
+  pub fn resolved_by(&self) -> Option<&str> {
+    self.transition("RESOLVED").and_then(|t| t.by.as_deref())
+  }
+
+  /// Returns how long the incident took to be acknowledged.
+  ///
+  /// Returns `None` if the incident has no start time or has not been
+  /// acknowledged.
+  #[cfg(feature = "chrono")]
+  pub fn time_to_ack(&self) -> Option<chrono::Duration> {
+    self.time_to("ACKED")
+  }
+
+  /// Returns how long the incident took to be resolved.
+  ///
+  /// Returns `None` if the incident has no start time or has not been resolved.
+  #[cfg(feature = "chrono")]
+  pub fn time_to_resolve(&self) -> Option<chrono::Duration> {
+    self.time_to("RESOLVED")
+  }
+
+  /// Returns how long the incident has been open: until it was resolved, or
+  /// until now if it is still open.
+  ///
+  /// Returns `None` if the incident has no start time.
+  #[cfg(feature = "chrono")]
+  pub fn duration(&self) -> Option<chrono::Duration> {
+    self
+      .time_to_resolve()
+      .or_else(|| Some(Utc::now() - self.start_time?))
+  }
+
+  #[cfg(feature = "chrono")]
+  fn time_to(&self, name: &str) -> Option<chrono::Duration> {
+    Some(self.transition(name)?.at? - self.start_time?)
+  }
+
+  /// Renders the incident as one line, such as
+  /// `#1234 UNACKED web-prod-01: disk full (3 alerts, 12m old)`.
+  ///
+  /// The age is measured from `start_time` to now and is only included with
+  /// the `chrono` feature. The `Display` implementation renders the same line.
+  pub fn summary(&self) -> String {
+    #[cfg(feature = "chrono")]
+    return self.summary_at(Utc::now());
+
+    #[cfg(not(feature = "chrono"))]
+    self.render_summary(None)
+  }
+
+  /// Renders the incident as one line, measuring its age at the given time.
+  ///
+  /// # Arguments
+  ///
+  /// * `now` - The time to measure the incident's age against
+  #[cfg(feature = "chrono")]
+  pub fn summary_at(&self, now: DateTime<Utc>) -> String {
+    let age = self
+      .start_time
+      .map(|start| format_age((now - start).num_seconds().max(0)));
+    self.render_summary(age)
+  }
+
+  fn render_summary(&self, age: Option<String>) -> String {
+    let subject = match (
+      self.host.as_deref(),
+      self
+        .entity_display_name
+        .as_deref()
+        .or(self.entity_id.as_deref()),
+    ) {
+      (Some(host), Some(name)) if host != name => format!("{}: {}", host, name),
+      (_, Some(name)) => name.to_string(),
+      (Some(host), None) => host.to_string(),
+      (None, None) => "unknown entity".to_string(),
+    };
+
+    let mut line = format!(
+      "#{} {} {}",
+      self.incident_number.as_deref().unwrap_or("?"),
+      self.current_phase.as_deref().unwrap_or("UNKNOWN"),
+      subject
+    );
+
+    let mut notes = Vec::new();
+    if let Some(count) = self.alert_count {
+      notes.push(format!(
+        "{} alert{}",
+        count,
+        if count == 1 { "" } else { "s" }
+      ));
+    }
+    if let Some(age) = age {
+      notes.push(format!("{} old", age));
+    }
+    if !notes.is_empty() {
+      line.push_str(&format!(" ({})", notes.join(", ")));
+    }
+
+    line
+  }
+}
+
+impl std::fmt::Display for Incident {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.summary())
+  }
+}
+
+/// Formats a number of seconds as a short age such as `45s`, `12m`, `3h`, or `2d`.
+#[cfg(feature = "chrono")]
+fn format_age(seconds: i64) -> String {
+  match seconds {
+    s if s < 60 => format!("{}s", s),
+    s if s < 3600 => format!("{}m", s / 60),
+    s if s < 86_400 => format!("{}h", s / 3600),
+    s => format!("{}d", s / 86_400),
+  }
+}
+
 /// Response containing a list of incidents.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct IncidentResponse {
   /// The list of incidents in the response.
   #[serde(default, skip_serializing_if = "Vec::is_empty")]
   pub incidents: Vec<Incident>,
 }
 
-/// Represents a user in VictorOps.
+impl_list_response!(IncidentResponse, incidents, Incident);
+
+/// Orders incidents by start time, putting incidents without one last.
+fn by_start_time(a: &Incident, b: &Incident) -> std::cmp::Ordering {
+  a.start_time
+    .is_none()
+    .cmp(&b.start_time.is_none())
+    .then_with(|| a.start_time.cmp(&b.start_time))
+}
+
+impl IncidentResponse {
+  /// Groups the incidents by the slugs of the teams they paged.
+  ///
+  /// An incident that paged several teams appears under each of them.
+  /// Incidents that paged no team are left out.
+  ///
+  /// # Returns
+  ///
+  /// A map from team slug to that team's incidents, in response order.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # async fn run(client: victorops::Client) -> victorops::ApiResult<()> {
+  /// let (incidents, _) = client.get_incidents().await?;
+  /// for (team, incidents) in incidents.group_by_team() {
+  ///   println!("{}: {} open", team, incidents.len());
+  /// }
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn group_by_team(&self) -> BTreeMap<&str, Vec<&Incident>> {
+    let mut groups: BTreeMap<&str, Vec<&Incident>> = BTreeMap::new();
+
+    for incident in &self.incidents {
+      for team in &incident.paged_teams {
+        groups.entry(team.as_str()).or_default().push(incident);
+      }
+    }
+
+    groups
+  }
+
+  /// Returns the integer incident numbers, skipping any that do not parse.
+  ///
+  /// See [`Incident::incident_number_i64`].
+  pub fn incident_numbers(&self) -> Vec<i64> {
+    self
+      .incidents
+      .iter()
+      .filter_map(Incident::incident_number_i64)
+      .collect()
+  }
+
+  /// Sorts the incidents from oldest to newest start time.
+  ///
+  /// Incidents without a start time are moved to the end.
+  pub fn sort_by_start_time(&mut self) {
+    self.incidents.sort_by(by_start_time);
+  }
+
+  /// Returns the longest-waiting incident that nobody has acknowledged.
+  ///
+  /// # Returns
+  ///
+  /// The `UNACKED` incident with the earliest start time, or `None` if every
+  /// incident has been acknowledged.
+  pub fn oldest_unacked(&self) -> Option<&Incident> {
+    self
+      .incidents
+      .iter()
+      .filter(|i| {
+        i.current_phase
+          .as_deref()
+          .is_some_and(|p| p.eq_ignore_ascii_case("UNACKED"))
+      })
+      .min_by(|a, b| by_start_time(a, b))
+  }
+}
+
+/// A page of historical incidents from the reporting API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct IncidentHistoryPage {
+  /// The offset of the first incident on this page.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub offset: Option<usize>,
+  /// The page size that was requested.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub limit: Option<usize>,
+  /// The total number of incidents matching the query.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub total: Option<usize>,
+  /// The incidents on this page.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub incidents: Vec<Incident>,
+}
+
+/// The kind of entity targeted when creating an incident.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum IncidentTargetType {
+  /// Page a specific user.
+  User,
+  /// Page an escalation policy.
+  EscalationPolicy,
+}
+
+/// A user or escalation policy to page for a new incident.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct IncidentTarget {
+  /// The kind of entity being targeted.
+  #[serde(rename = "type")]
+  pub target_type: IncidentTargetType,
+  /// The username or escalation policy slug to page.
+  pub slug: String,
+}
+
+impl IncidentTarget {
+  /// Creates a target that pages a user.
+  pub fn user(username: &str) -> Self {
+    IncidentTarget {
+      target_type: IncidentTargetType::User,
+      slug: username.to_string(),
+    }
+  }
+
+  /// Creates a target that pages an escalation policy.
+  pub fn escalation_policy(policy_slug: &str) -> Self {
+    IncidentTarget {
+      target_type: IncidentTargetType::EscalationPolicy,
+      slug: policy_slug.to_string(),
+    }
+  }
+}
+
+/// Request to manually create an incident.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CreateIncidentRequest {
+  /// A short summary of the incident.
+  pub summary: String,
+  /// The full details of the incident.
+  pub details: String,
+  /// The username of the user creating the incident.
+  #[serde(rename = "userName")]
+  pub user_name: String,
+  /// The users and escalation policies to page. Always sent; the API
+  /// requires at least one.
+  pub targets: Vec<IncidentTarget>,
+  /// Whether multiple responders may acknowledge the incident.
+  #[serde(rename = "isMultiResponder")]
+  pub is_multi_responder: bool,
+}
+
+/// Response from manually creating an incident.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CreateIncidentResponse {
+  /// The number of the created incident.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "incidentNumber")]
+  pub incident_number: Option<String>,
+  /// An error message if the incident could not be created.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error: Option<String>,
+}
+
+/// Request to acknowledge or resolve a batch of incidents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct IncidentActionRequest {
+  /// The username of the user taking the action.
+  #[serde(rename = "userName")]
+  pub user_name: String,
+  /// The numbers of the incidents to act on. Always sent; the API requires
+  /// at least one.
+  #[serde(rename = "incidentNames")]
+  pub incident_names: Vec<String>,
+  /// An optional message to attach to the action.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
+}
+
+impl IncidentActionRequest {
+  /// Builds a request acting on the given incident numbers.
+  ///
+  /// # Arguments
+  ///
+  /// * `user` - The username of the user taking the action
+  /// * `incident_numbers` - The numbers of the incidents to act on
+  /// * `message` - An optional message to attach to the action
+  pub fn new(user: &str, incident_numbers: &[i64], message: Option<&str>) -> Self {
+    IncidentActionRequest {
+      user_name: user.to_string(),
+      incident_names: incident_numbers.iter().map(|n| n.to_string()).collect(),
+      message: message.map(str::to_string),
+    }
+  }
+}
+
+/// The outcome of acknowledging or resolving a single incident.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct IncidentActionResult {
+  /// The number of the incident.
+  #[serde(rename = "incidentNumber")]
+  pub incident_number: String,
+  /// The entity ID of the incident.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "entityId")]
+  pub entity_id: Option<String>,
+  /// Whether the command was accepted for this incident.
+  #[serde(default, rename = "cmdAccepted")]
+  pub cmd_accepted: bool,
+  /// A message describing the outcome.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
+}
+
+/// Response from acknowledging or resolving incidents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct IncidentActionResponse {
+  /// The per-incident outcomes.
+  #[serde(default)]
+  pub results: Vec<IncidentActionResult>,
+}
+
+impl IncidentActionResponse {
+  /// Returns the results for incidents whose command was not accepted.
+  pub fn failures(&self) -> Vec<&IncidentActionResult> {
+    self.results.iter().filter(|r| !r.cmd_accepted).collect()
+  }
+
+  /// Returns true if the command was accepted for every incident.
+  pub fn all_accepted(&self) -> bool {
+    self.results.iter().all(|r| r.cmd_accepted)
+  }
+}
+
+/// A note attached to an incident.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct IncidentNote {
+  /// The identifier of the note.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// The text of the note.
+  pub content: String,
+  /// The username of the user who wrote the note.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "createdBy")]
+  pub created_by: Option<String>,
+  /// When the note was written.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "createdAt")]
+  pub created_at: Option<String>,
+}
+
+/// Represents a user in VictorOps.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct User {
   /// The first name of the user.
   #[serde(skip_serializing_if = "Option::is_none", rename = "firstName")]
@@ -158,24 +848,299 @@ pub struct User {
   /// Whether the user's account has been verified.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub verified: Option<bool>,
+  /// The user's organization role.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub role: Option<UserRole>,
+}
+
+/// A user's organization-wide role, which determines their permissions.
+///
+/// Only the exact wire values are recognized. Any other value, including a
+/// known role spelled differently, is preserved in [`UserRole::Unknown`], so
+/// a fetched user sent back through `update_user` keeps its role unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UserRole {
+  /// Can manage every part of the organization.
+  Admin,
+  /// An administrator reported under the `global_admin` name.
+  GlobalAdmin,
+  /// Can manage alert rules, integrations, and routing.
+  AlertAdmin,
+  /// Can be paged and respond to incidents.
+  User,
+  /// Can view incidents and receive updates, but cannot be paged.
+  Stakeholder,
+  /// A role not known to this client.
+  Unknown(String),
+}
+
+impl UserRole {
+  /// Returns the wire representation of this role.
+  pub fn as_str(&self) -> &str {
+    match self {
+      UserRole::Admin => "admin",
+      UserRole::GlobalAdmin => "global_admin",
+      UserRole::AlertAdmin => "alert_admin",
+      UserRole::User => "user",
+      UserRole::Stakeholder => "stakeholder",
+      UserRole::Unknown(value) => value,
+    }
+  }
+}
+
+impl From<&str> for UserRole {
+  fn from(value: &str) -> Self {
+    match value {
+      "admin" => UserRole::Admin,
+      "global_admin" => UserRole::GlobalAdmin,
+      "alert_admin" => UserRole::AlertAdmin,
+      "user" => UserRole::User,
+      "stakeholder" => UserRole::Stakeholder,
+      _ => UserRole::Unknown(value.to_string()),
+    }
+  }
+}
+
+impl Serialize for UserRole {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(self.as_str())
+  }
+}
+
+impl<'de> Deserialize<'de> for UserRole {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(UserRole::from(value.as_str()))
+  }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for UserRole {
+  fn schema_name() -> Cow<'static, str> {
+    "UserRole".into()
+  }
+
+  fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+      "type": "string",
+      "examples": ["admin", "global_admin", "alert_admin", "user", "stakeholder"]
+    })
+  }
+}
+
+impl User {
+  /// Returns the user's role, falling back to the `admin` flag.
+  ///
+  /// Responses that omit `role` but set `admin` to `true` are treated as
+  /// [`UserRole::Admin`]. `admin: false` does not identify a role, since
+  /// users, alert admins, and stakeholders all carry it.
+  ///
+  /// # Returns
+  ///
+  /// The role, or `None` if it cannot be determined.
+  pub fn effective_role(&self) -> Option<UserRole> {
+    match (&self.role, self.admin) {
+      (Some(role), _) => Some(role.clone()),
+      (None, Some(true)) => Some(UserRole::Admin),
+      (None, _) => None,
+    }
+  }
+
+  /// Returns a builder for a user with the given username and email.
+  ///
+  /// # Arguments
+  ///
+  /// * `username` - The username of the new user
+  /// * `email` - The email address of the new user
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use victorops::User;
+  ///
+  /// let user = User::builder("jdoe", "john.doe@example.com")
+  ///   .first_name("John")
+  ///   .last_name("Doe")
+  ///   .build()?;
+  /// # Ok::<(), victorops::Error>(())
+  /// ```
+  pub fn builder(username: impl Into<String>, email: impl Into<String>) -> UserBuilder {
+    UserBuilder {
+      username: username.into(),
+      email: email.into(),
+      first_name: None,
+      last_name: None,
+      admin: None,
+      expiration_hours: None,
+    }
+  }
+}
+
+/// Builds a [`User`] for creating or updating a user.
+#[derive(Debug, Clone)]
+pub struct UserBuilder {
+  username: String,
+  email: String,
+  first_name: Option<String>,
+  last_name: Option<String>,
+  admin: Option<bool>,
+  expiration_hours: Option<i32>,
+}
+
+impl UserBuilder {
+  /// Sets the first name.
+  ///
+  /// # Arguments
+  ///
+  /// * `first_name` - The user's first name
+  pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+    self.first_name = Some(first_name.into());
+    self
+  }
+
+  /// Sets the last name.
+  ///
+  /// # Arguments
+  ///
+  /// * `last_name` - The user's last name
+  pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+    self.last_name = Some(last_name.into());
+    self
+  }
+
+  /// Sets whether the user has admin privileges.
+  ///
+  /// # Arguments
+  ///
+  /// * `admin` - Whether the user is an admin
+  pub fn admin(mut self, admin: bool) -> Self {
+    self.admin = Some(admin);
+    self
+  }
+
+  /// Sets the number of hours until the user's session expires.
+  ///
+  /// # Arguments
+  ///
+  /// * `hours` - The session lifetime in hours
+  pub fn expiration_hours(mut self, hours: i32) -> Self {
+    self.expiration_hours = Some(hours);
+    self
+  }
+
+  /// Validates the user and constructs it.
+  ///
+  /// # Returns
+  ///
+  /// The user, or `Error::InvalidInput` if the username is empty or the
+  /// email address is malformed.
+  pub fn build(self) -> ApiResult<User> {
+    let user = User {
+      first_name: self.first_name,
+      last_name: self.last_name,
+      username: Some(self.username),
+      email: Some(self.email),
+      admin: self.admin,
+      expiration_hours: self.expiration_hours,
+      created_at: None,
+      password_last_updated: None,
+      verified: None,
+      role: None,
+    };
+    user.validate()?;
+    Ok(user)
+  }
 }
 
 /// Response containing a list of users (v1 API format).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct UserList {
   /// The nested list of users in v1 API format.
   pub users: Vec<Vec<User>>,
 }
 
+impl UserList {
+  /// Returns an iterator over every user, hiding the nested v1 wire format.
+  pub fn iter(&self) -> impl Iterator<Item = &User> {
+    self.users.iter().flatten()
+  }
+
+  /// Consumes the list and returns every user in a single vector.
+  pub fn into_flat(self) -> Vec<User> {
+    self.users.into_iter().flatten().collect()
+  }
+}
+
 /// Response containing a list of users (v2 API format).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct UserListV2 {
   /// The list of users in v2 API format.
   pub users: Vec<User>,
 }
 
-/// Represents a team in VictorOps.
+impl_list_response!(UserListV2, users, User);
+
+/// A user's personal paging policy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PagingPolicy {
+  /// The steps of the paging policy, in order.
+  #[serde(default)]
+  pub steps: Vec<PagingPolicyStep>,
+}
+
+/// A step in a user's paging policy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PagingPolicyStep {
+  /// The position of the step in the policy.
+  pub index: i32,
+  /// The notification rules run during this step.
+  #[serde(default)]
+  pub rules: Vec<PagingPolicyRule>,
+}
+
+/// A notification rule within a paging policy step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PagingPolicyRule {
+  /// The position of the rule in the step.
+  pub index: i32,
+  /// The kind of notification sent, such as `email`, `sms`, or `push`.
+  #[serde(rename = "type")]
+  pub rule_type: String,
+  /// The contact method notified, if the rule targets one.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub contact: Option<PagingPolicyContact>,
+  /// The number of minutes to wait before moving to the next rule.
+  #[serde(default)]
+  pub timeout: i32,
+}
+
+/// The contact method targeted by a paging policy rule.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PagingPolicyContact {
+  /// The identifier of the contact method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id: Option<i64>,
+  /// The kind of contact method.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
+  pub contact_type: Option<String>,
+}
+
+/// Represents a team in VictorOps.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Team {
   /// The name of the team.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -194,50 +1159,237 @@ pub struct Team {
   pub is_default_team: Option<bool>,
 }
 
+impl Team {
+  /// Creates a team with the given name, for use with `create_team`.
+  ///
+  /// # Arguments
+  ///
+  /// * `name` - The name of the team
+  pub fn new(name: impl Into<String>) -> Self {
+    Team {
+      name: Some(name.into()),
+      slug: None,
+      member_count: None,
+      version: None,
+      is_default_team: None,
+    }
+  }
+}
+
+/// Response containing the teams a user belongs to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct UserTeams {
+  /// The teams the user belongs to.
+  #[serde(default)]
+  pub teams: Vec<Team>,
+}
+
 /// Response containing team members.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct TeamMembers {
   /// The list of team members.
   #[serde(default, skip_serializing_if = "Vec::is_empty")]
-  pub members: Vec<User>,
+  pub members: Vec<TeamMember>,
 }
 
-/// Represents an admin user.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Admin {
-  /// The username of the admin.
+impl_list_response!(TeamMembers, members, TeamMember);
+
+/// A member of a team, as listed by the team members endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct TeamMember {
+  /// The username of the member.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub username: Option<String>,
-  /// The first name of the admin.
+  /// The first name of the member.
   #[serde(skip_serializing_if = "Option::is_none", rename = "firstName")]
   pub first_name: Option<String>,
-  /// The last name of the admin.
+  /// The last name of the member.
   #[serde(skip_serializing_if = "Option::is_none", rename = "lastName")]
   pub last_name: Option<String>,
-  /// The URL to the admin's profile.
-  #[serde(skip_serializing_if = "Option::is_none", rename = "_selfUrl")]
-  pub self_url: Option<String>,
+  /// The member's full name as shown in VictorOps.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "displayName")]
+  pub display_name: Option<String>,
+  /// The email address of the member.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub email: Option<String>,
+  /// Whether the member's account has been verified.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub verified: Option<bool>,
+  /// The member's role on the team, such as `member` or `admin`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub role: Option<String>,
+  /// The version of the member's user record.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub version: Option<i32>,
+  /// The URL to the member's profile.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "_selfUrl")]
+  pub self_url: Option<Link>,
+}
+
+/// A link to another API resource, such as a `_selfUrl`.
+///
+/// The API usually returns paths such as `/api-public/v1/user/jdoe`, which
+/// are kept as [`Link::Relative`]. Absolute links parse into a [`url::Url`].
+/// Fetch the linked resource with [`Client::follow`](crate::Client::follow).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Link {
+  /// An absolute URL.
+  Absolute(url::Url),
+  /// A path, or any other value that is not an absolute URL.
+  Relative(String),
+}
+
+impl Link {
+  /// Parses a link, keeping values that are not absolute URLs as they are.
+  pub fn parse(value: &str) -> Self {
+    match url::Url::parse(value) {
+      Ok(url) => Link::Absolute(url),
+      Err(_) => Link::Relative(value.to_string()),
+    }
+  }
+
+  /// Returns the link as it appears on the wire.
+  pub fn as_str(&self) -> &str {
+    match self {
+      Link::Absolute(url) => url.as_str(),
+      Link::Relative(path) => path,
+    }
+  }
+
+  /// Resolves the link against a base URL.
+  ///
+  /// # Returns
+  ///
+  /// The absolute URL, or `None` if a relative link cannot be joined to `base`.
+  pub fn resolve(&self, base: &url::Url) -> Option<url::Url> {
+    match self {
+      Link::Absolute(url) => Some(url.clone()),
+      Link::Relative(path) => base.join(path).ok(),
+    }
+  }
+}
+
+impl std::fmt::Display for Link {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl Serialize for Link {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(self.as_str())
+  }
+}
+
+impl<'de> Deserialize<'de> for Link {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(Link::parse(&value))
+  }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Link {
+  fn schema_name() -> Cow<'static, str> {
+    "Link".into()
+  }
+
+  fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+      "type": "string",
+      "format": "uri-reference"
+    })
+  }
+}
+
+/// Represents an admin user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Admin {
+  /// The username of the admin.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub username: Option<String>,
+  /// The first name of the admin.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "firstName")]
+  pub first_name: Option<String>,
+  /// The last name of the admin.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "lastName")]
+  pub last_name: Option<String>,
+  /// The URL to the admin's profile.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "_selfUrl")]
+  pub self_url: Option<Link>,
 }
 
 /// Response containing team administrators.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct TeamAdmins {
   /// The list of team administrators.
   #[serde(default, skip_serializing_if = "Vec::is_empty")]
   pub admin: Vec<Admin>,
 }
 
+/// Reads a contact method ID from a JSON number or numeric string.
+///
+/// The API sometimes encodes IDs as floats such as `12345.0`; those are
+/// accepted when they have no fractional part.
+pub(crate) fn contact_id(value: &serde_json::Value) -> Option<i64> {
+  match value {
+    serde_json::Value::Number(number) => number.as_i64().or_else(|| {
+      number
+        .as_f64()
+        .filter(|f| f.fract() == 0.0 && *f >= i64::MIN as f64 && *f < i64::MAX as f64)
+        .map(|f| f as i64)
+    }),
+    serde_json::Value::String(text) => text.trim().parse().ok(),
+    _ => None,
+  }
+}
+
+fn deserialize_contact_id<'de, D: serde::Deserializer<'de>>(
+  deserializer: D,
+) -> Result<i64, D::Error> {
+  let value = serde_json::Value::deserialize(deserializer)?;
+  contact_id(&value)
+    .ok_or_else(|| serde::de::Error::custom(format!("invalid contact method ID {}", value)))
+}
+
+fn deserialize_optional_contact_id<'de, D: serde::Deserializer<'de>>(
+  deserializer: D,
+) -> Result<Option<i64>, D::Error> {
+  match Option::<serde_json::Value>::deserialize(deserializer)? {
+    None => Ok(None),
+    Some(value) => contact_id(&value)
+      .map(Some)
+      .ok_or_else(|| serde::de::Error::custom(format!("invalid contact method ID {}", value))),
+  }
+}
+
 /// Represents a contact method.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct ContactMethod {
   /// The unique identifier of the contact method.
-  pub id: f64,
+  #[serde(deserialize_with = "deserialize_contact_id")]
+  #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
+  pub id: i64,
   /// The label or name of the contact method.
   pub label: String,
 }
 
 /// Response containing email contact methods.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct EmailsResponse {
   /// The list of email contact methods.
   #[serde(rename = "contactMethods")]
@@ -246,6 +1398,8 @@ pub struct EmailsResponse {
 
 /// Represents a team in API responses.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct ApiTeam {
   /// The name of the team.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -257,6 +1411,8 @@ pub struct ApiTeam {
 
 /// Represents an escalation policy in API responses.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct ApiEscalationPolicy {
   /// The name of the escalation policy.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -267,15 +1423,102 @@ pub struct ApiEscalationPolicy {
 }
 
 /// Represents a user in API responses.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Schedules and overrides only identify users by username. Convert a
+/// [`User`] or [`Admin`] with `ApiUser::from` to compare them with schedule
+/// entries.
+///
+/// # Examples
+///
+/// ```
+/// use victorops::{ApiUser, User};
+///
+/// let user = User::builder("jdoe", "jdoe@example.com").build().unwrap();
+/// assert_eq!(ApiUser::from(&user), ApiUser::new("jdoe"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct ApiUser {
   /// The username of the user.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub username: Option<String>,
 }
 
+impl ApiUser {
+  /// Creates an API user reference for the given username.
+  pub fn new(username: impl Into<String>) -> Self {
+    ApiUser {
+      username: Some(username.into()),
+    }
+  }
+}
+
+impl From<&User> for ApiUser {
+  fn from(user: &User) -> Self {
+    ApiUser {
+      username: user.username.clone(),
+    }
+  }
+}
+
+impl From<User> for ApiUser {
+  fn from(user: User) -> Self {
+    ApiUser {
+      username: user.username,
+    }
+  }
+}
+
+impl From<&TeamMember> for ApiUser {
+  fn from(member: &TeamMember) -> Self {
+    ApiUser {
+      username: member.username.clone(),
+    }
+  }
+}
+
+impl From<&Admin> for ApiUser {
+  fn from(admin: &Admin) -> Self {
+    ApiUser {
+      username: admin.username.clone(),
+    }
+  }
+}
+
+impl From<Admin> for ApiUser {
+  fn from(admin: Admin) -> Self {
+    ApiUser {
+      username: admin.username,
+    }
+  }
+}
+
+impl From<Admin> for User {
+  /// Converts a team admin into a user carrying its username and name.
+  ///
+  /// The admin's profile URL is dropped, and `admin` stays `None` because
+  /// team admins are not necessarily organization admins.
+  fn from(admin: Admin) -> Self {
+    User {
+      first_name: admin.first_name,
+      last_name: admin.last_name,
+      username: admin.username,
+      email: None,
+      admin: None,
+      expiration_hours: None,
+      created_at: None,
+      password_last_updated: None,
+      verified: None,
+      role: None,
+    }
+  }
+}
+
 /// Represents an on-call override.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct ApiOnCallOverride {
   /// The original user who was scheduled to be on-call.
   #[serde(skip_serializing_if = "Option::is_none", rename = "origOnCallUser")]
@@ -285,24 +1528,56 @@ pub struct ApiOnCallOverride {
   pub override_on_call_user: Option<ApiUser>,
   /// The start time of the on-call override.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub start: Option<DateTime<Utc>>,
+  pub start: Option<Timestamp>,
   /// The end time of the on-call override.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub end: Option<DateTime<Utc>>,
+  pub end: Option<Timestamp>,
   /// The escalation policy associated with this override.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub policy: Option<ApiEscalationPolicy>,
 }
 
+impl ApiOnCallOverride {
+  /// Returns whether the override is in effect at the given time.
+  ///
+  /// The override window is half-open: it includes `start` and excludes `end`.
+  /// Overrides missing either bound are never considered active.
+  #[cfg(feature = "chrono")]
+  pub fn is_active_at(&self, at: DateTime<Utc>) -> bool {
+    match (self.start, self.end) {
+      (Some(start), Some(end)) => start <= at && at < end,
+      _ => false,
+    }
+  }
+
+  /// Returns whether `username` is the user originally scheduled during this override.
+  pub fn is_original_user(&self, username: &str) -> bool {
+    username_matches(self.orig_on_call_user.as_ref(), username)
+  }
+
+  /// Returns whether `username` is the user covering this override.
+  pub fn is_override_user(&self, username: &str) -> bool {
+    username_matches(self.override_on_call_user.as_ref(), username)
+  }
+}
+
+fn username_matches(user: Option<&ApiUser>, username: &str) -> bool {
+  user
+    .and_then(|u| u.username.as_deref())
+    .is_some_and(|name| name.eq_ignore_ascii_case(username))
+}
+
 /// Represents an on-call roll/rotation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct ApiOnCallRoll {
   /// The start time of the on-call period.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub start: Option<DateTime<Utc>>,
+  pub start: Option<Timestamp>,
   /// The end time of the on-call period.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub end: Option<DateTime<Utc>>,
+  pub end: Option<Timestamp>,
   /// The user who is on-call during this period.
   #[serde(skip_serializing_if = "Option::is_none", rename = "onCallUser")]
   pub on_call_user: Option<ApiUser>,
@@ -311,8 +1586,76 @@ pub struct ApiOnCallRoll {
   pub is_roll: Option<bool>,
 }
 
+/// The type of an on-call schedule entry.
+///
+/// Values the client does not recognize are preserved in
+/// [`OnCallType::Unknown`] so they round-trip unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OnCallType {
+  /// A user is directly on-call.
+  User,
+  /// An override replaces the scheduled user.
+  Override,
+  /// The on-call user comes from a rotation group.
+  Rotation,
+  /// An on-call type not known to this client.
+  Unknown(String),
+}
+
+impl OnCallType {
+  /// Returns the wire representation of this on-call type.
+  pub fn as_str(&self) -> &str {
+    match self {
+      OnCallType::User => "user",
+      OnCallType::Override => "override",
+      OnCallType::Rotation => "rotation_group",
+      OnCallType::Unknown(value) => value,
+    }
+  }
+}
+
+impl From<&str> for OnCallType {
+  fn from(value: &str) -> Self {
+    match value.to_ascii_lowercase().as_str() {
+      "user" => OnCallType::User,
+      "override" => OnCallType::Override,
+      "rotation_group" | "rotationgroup" | "rotation" => OnCallType::Rotation,
+      _ => OnCallType::Unknown(value.to_string()),
+    }
+  }
+}
+
+impl Serialize for OnCallType {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(self.as_str())
+  }
+}
+
+impl<'de> Deserialize<'de> for OnCallType {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(OnCallType::from(value.as_str()))
+  }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for OnCallType {
+  fn schema_name() -> Cow<'static, str> {
+    "OnCallType".into()
+  }
+
+  fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+      "type": "string",
+      "examples": ["user", "override", "rotation_group"]
+    })
+  }
+}
+
 /// Represents an on-call schedule entry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct ApiOnCallEntry {
   /// The user who is scheduled to be on-call.
   #[serde(skip_serializing_if = "Option::is_none", rename = "onCallUser")]
@@ -322,7 +1665,7 @@ pub struct ApiOnCallEntry {
   pub override_on_call_user: Option<ApiUser>,
   /// The type of on-call assignment.
   #[serde(skip_serializing_if = "Option::is_none", rename = "onCallType")]
-  pub on_call_type: Option<String>,
+  pub on_call_type: Option<OnCallType>,
   /// The name of the rotation this entry belongs to.
   #[serde(skip_serializing_if = "Option::is_none", rename = "rotationName")]
   pub rotation_name: Option<String>,
@@ -331,7 +1674,7 @@ pub struct ApiOnCallEntry {
   pub shift_name: Option<String>,
   /// The timestamp when the shift roll occurs.
   #[serde(skip_serializing_if = "Option::is_none", rename = "shiftRoll")]
-  pub shift_roll: Option<DateTime<Utc>>,
+  pub shift_roll: Option<Timestamp>,
   /// The list of rolls/rotations for this entry.
   #[serde(default, skip_serializing_if = "Vec::is_empty")]
   pub rolls: Vec<ApiOnCallRoll>,
@@ -339,6 +1682,8 @@ pub struct ApiOnCallEntry {
 
 /// Represents an escalation policy schedule.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct ApiEscalationPolicySchedule {
   /// The escalation policy this schedule belongs to.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -351,8 +1696,32 @@ pub struct ApiEscalationPolicySchedule {
   pub overrides: Vec<ApiOnCallOverride>,
 }
 
+impl ApiEscalationPolicySchedule {
+  /// Returns the overrides in effect at the given time.
+  #[cfg(feature = "chrono")]
+  pub fn active_overrides_at(&self, at: DateTime<Utc>) -> Vec<&ApiOnCallOverride> {
+    self
+      .overrides
+      .iter()
+      .filter(|o| o.is_active_at(at))
+      .collect()
+  }
+
+  /// Returns the overrides involving a user, either as the originally
+  /// scheduled user or as the user covering the override.
+  pub fn overrides_for_user(&self, username: &str) -> Vec<&ApiOnCallOverride> {
+    self
+      .overrides
+      .iter()
+      .filter(|o| o.is_original_user(username) || o.is_override_user(username))
+      .collect()
+  }
+}
+
 /// Represents a team's on-call schedule.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct ApiTeamSchedule {
   /// The team this schedule belongs to.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -362,8 +1731,32 @@ pub struct ApiTeamSchedule {
   pub schedules: Vec<ApiEscalationPolicySchedule>,
 }
 
+impl ApiTeamSchedule {
+  /// Returns the overrides in effect at the given time across all policies.
+  #[cfg(feature = "chrono")]
+  pub fn active_overrides_at(&self, at: DateTime<Utc>) -> Vec<&ApiOnCallOverride> {
+    self
+      .schedules
+      .iter()
+      .flat_map(|s| s.active_overrides_at(at))
+      .collect()
+  }
+
+  /// Returns the overrides involving a user across all policies, either as
+  /// the originally scheduled user or as the user covering the override.
+  pub fn overrides_for_user(&self, username: &str) -> Vec<&ApiOnCallOverride> {
+    self
+      .schedules
+      .iter()
+      .flat_map(|s| s.overrides_for_user(username))
+      .collect()
+  }
+}
+
 /// Represents a user's on-call schedule.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct ApiUserSchedule {
   /// The team schedules for this user.
   #[serde(
@@ -374,8 +1767,55 @@ pub struct ApiUserSchedule {
   pub schedules: Vec<ApiTeamSchedule>,
 }
 
+/// The users currently on call across the organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CurrentOnCall {
+  /// The current on-call assignments for each team.
+  #[serde(default, rename = "teamsOnCall")]
+  pub teams_on_call: Vec<TeamOnCall>,
+}
+
+/// The current on-call assignments for a single team.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct TeamOnCall {
+  /// The team.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub team: Option<ApiTeam>,
+  /// The users on call for each of the team's escalation policies.
+  #[serde(default, rename = "oncallNow")]
+  pub oncall_now: Vec<PolicyOnCall>,
+}
+
+/// The users currently on call for a single escalation policy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PolicyOnCall {
+  /// The escalation policy.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "escalationPolicy")]
+  pub escalation_policy: Option<ApiEscalationPolicy>,
+  /// The users on call for the policy.
+  #[serde(default)]
+  pub users: Vec<OnCallUser>,
+}
+
+/// A user currently on call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct OnCallUser {
+  /// The on-call user.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "onCalluser")]
+  pub on_call_user: Option<ApiUser>,
+}
+
 /// Request to take on-call duty.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TakeRequest {
   /// The user who is giving up on-call duty.
   #[serde(skip_serializing_if = "Option::is_none", rename = "fromUser")]
@@ -385,39 +1825,141 @@ pub struct TakeRequest {
   pub to_user: Option<String>,
 }
 
+impl TakeRequest {
+  /// Creates a take request, checking that both users are present.
+  ///
+  /// # Arguments
+  ///
+  /// * `from_user` - The username of the user giving up on-call duty
+  /// * `to_user` - The username of the user taking on-call duty
+  ///
+  /// # Returns
+  ///
+  /// The take request, or `Error::InvalidInput` if either username is empty.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use victorops::TakeRequest;
+  ///
+  /// let request = TakeRequest::new("jdoe", "asmith")?;
+  /// assert!(TakeRequest::new("jdoe", "").is_err());
+  /// # Ok::<(), victorops::Error>(())
+  /// ```
+  pub fn new(from_user: impl Into<String>, to_user: impl Into<String>) -> ApiResult<Self> {
+    let request = TakeRequest {
+      from_user: Some(from_user.into()),
+      to_user: Some(to_user.into()),
+    };
+    request.validate()?;
+    Ok(request)
+  }
+}
+
 /// Response from taking on-call duty.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct TakeResponse {
   /// The result of the take request.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub result: Option<String>,
 }
 
-/// Represents an entry in an escalation policy step.
+/// Request to schedule an on-call override for a user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CreateScheduledOverrideRequest {
+  /// The username of the user going off call.
+  pub username: String,
+  /// The timezone the override is scheduled in.
+  pub timezone: String,
+  /// When the override begins.
+  pub start: Timestamp,
+  /// When the override ends.
+  pub end: Timestamp,
+}
+
+/// A scheduled on-call override.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct ScheduledOverride {
+  /// The public identifier of the override.
+  #[serde(rename = "publicId")]
+  pub public_id: String,
+  /// The user going off call.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub user: Option<ApiUser>,
+  /// The timezone the override is scheduled in.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub timezone: Option<String>,
+  /// When the override begins.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub start: Option<Timestamp>,
+  /// When the override ends.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub end: Option<Timestamp>,
+  /// Who covers each affected escalation policy during the override.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub assignments: Vec<OverrideAssignment>,
+}
+
+/// Who covers a single escalation policy during a scheduled override.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct OverrideAssignment {
+  /// The escalation policy being covered.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub policy: Option<ApiEscalationPolicy>,
+  /// The team the escalation policy belongs to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub team: Option<ApiTeam>,
+  /// The username of the user covering the policy, if one is assigned.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "assignedUser")]
+  pub assigned_user: Option<String>,
+}
+
+/// A list of assignments for a scheduled override.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct OverrideAssignmentList {
+  /// The assignments for each affected escalation policy.
+  #[serde(default)]
+  pub assignments: Vec<OverrideAssignment>,
+}
+
+/// Represents an entry in an escalation policy step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct EscalationPolicyStepEntry {
   /// The type of execution for this escalation step.
   #[serde(skip_serializing_if = "Option::is_none", rename = "executionType")]
   pub execution_type: Option<String>,
   /// User information for user-based escalation targets.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub user: Option<std::collections::HashMap<String, String>>,
+  pub user: Option<BTreeMap<String, String>>,
   /// Rotation group information for rotation-based escalation targets.
   #[serde(skip_serializing_if = "Option::is_none", rename = "rotationGroup")]
-  pub rotation_group: Option<std::collections::HashMap<String, String>>,
+  pub rotation_group: Option<BTreeMap<String, String>>,
   /// Webhook information for webhook-based escalation targets.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub webhook: Option<std::collections::HashMap<String, String>>,
+  pub webhook: Option<BTreeMap<String, String>>,
   /// Email information for email-based escalation targets.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub email: Option<std::collections::HashMap<String, String>>,
+  pub email: Option<BTreeMap<String, String>>,
   /// Target policy information for policy-based escalation targets.
   #[serde(skip_serializing_if = "Option::is_none", rename = "targetPolicy")]
-  pub target_policy: Option<std::collections::HashMap<String, String>>,
+  pub target_policy: Option<BTreeMap<String, String>>,
 }
 
 /// Represents a step in an escalation policy.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct EscalationPolicySteps {
   /// The timeout in seconds before escalating to the next step.
   pub timeout: i32,
@@ -426,7 +1968,9 @@ pub struct EscalationPolicySteps {
 }
 
 /// Represents an escalation policy.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct EscalationPolicy {
   /// The name of the escalation policy.
   pub name: String,
@@ -439,12 +1983,199 @@ pub struct EscalationPolicy {
   /// The escalation steps for this policy.
   pub steps: Vec<EscalationPolicySteps>,
   /// The unique slug/ID of the escalation policy.
-  #[serde(rename = "slug")]
+  ///
+  /// Left empty when creating a policy so the API assigns one.
+  #[serde(rename = "slug", default, skip_serializing_if = "String::is_empty")]
   pub id: String,
 }
 
+impl EscalationPolicy {
+  /// Returns a builder for an escalation policy owned by the given team.
+  ///
+  /// # Arguments
+  ///
+  /// * `name` - The name of the escalation policy
+  /// * `team_id` - The slug of the team that owns the policy
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use victorops::{EscalationPolicy, EscalationTarget};
+  ///
+  /// let policy = EscalationPolicy::builder("Database Primary", "team-db")
+  ///   .step(0, [EscalationTarget::rotation_group("rtg-db-primary")])
+  ///   .step(15, [EscalationTarget::user("jdoe"), EscalationTarget::email("dba@example.com")])
+  ///   .build()?;
+  /// # Ok::<(), victorops::Error>(())
+  /// ```
+  pub fn builder(name: impl Into<String>, team_id: impl Into<String>) -> EscalationPolicyBuilder {
+    EscalationPolicyBuilder {
+      name: name.into(),
+      team_id: team_id.into(),
+      ignore_custom_paging_policies: false,
+      steps: Vec::new(),
+    }
+  }
+}
+
+/// Builds an [`EscalationPolicy`] from typed escalation steps.
+#[derive(Debug, Clone)]
+pub struct EscalationPolicyBuilder {
+  name: String,
+  team_id: String,
+  ignore_custom_paging_policies: bool,
+  steps: Vec<EscalationPolicySteps>,
+}
+
+impl EscalationPolicyBuilder {
+  /// Appends an escalation step.
+  ///
+  /// # Arguments
+  ///
+  /// * `timeout` - The timeout before this step runs; only the first step may use `0`
+  /// * `targets` - The targets notified by this step
+  pub fn step(mut self, timeout: i32, targets: impl IntoIterator<Item = EscalationTarget>) -> Self {
+    self.steps.push(EscalationPolicySteps {
+      timeout,
+      entries: targets.into_iter().map(Into::into).collect(),
+    });
+    self
+  }
+
+  /// Sets whether to ignore custom paging policies.
+  ///
+  /// # Arguments
+  ///
+  /// * `ignore` - Whether to ignore custom paging policies
+  pub fn ignore_custom_paging_policies(mut self, ignore: bool) -> Self {
+    self.ignore_custom_paging_policies = ignore;
+    self
+  }
+
+  /// Validates the escalation policy and constructs it.
+  ///
+  /// # Returns
+  ///
+  /// The escalation policy, or `Error::InvalidInput` if the name or team is
+  /// empty or a step timeout is invalid.
+  pub fn build(self) -> ApiResult<EscalationPolicy> {
+    let policy = EscalationPolicy {
+      name: self.name,
+      team_id: self.team_id,
+      ignore_custom_paging_policies: self.ignore_custom_paging_policies,
+      steps: self.steps,
+      id: String::new(),
+    };
+    policy.validate()?;
+    Ok(policy)
+  }
+}
+
+/// A target notified by an escalation policy step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EscalationTarget {
+  /// Page a specific user by username.
+  User(String),
+  /// Page whoever is on-call for a rotation group, by slug.
+  RotationGroup(String),
+  /// Call a webhook, by slug.
+  Webhook(String),
+  /// Send an email to an address.
+  Email(String),
+  /// Escalate to another escalation policy, by slug.
+  Policy(String),
+}
+
+impl EscalationTarget {
+  /// Creates a target that pages a user.
+  ///
+  /// # Arguments
+  ///
+  /// * `username` - The username to page
+  pub fn user(username: impl Into<String>) -> Self {
+    EscalationTarget::User(username.into())
+  }
+
+  /// Creates a target that pages whoever is on-call for a rotation group.
+  ///
+  /// # Arguments
+  ///
+  /// * `slug` - The slug of the rotation group
+  pub fn rotation_group(slug: impl Into<String>) -> Self {
+    EscalationTarget::RotationGroup(slug.into())
+  }
+
+  /// Creates a target that calls a webhook.
+  ///
+  /// # Arguments
+  ///
+  /// * `slug` - The slug of the webhook
+  pub fn webhook(slug: impl Into<String>) -> Self {
+    EscalationTarget::Webhook(slug.into())
+  }
+
+  /// Creates a target that sends an email.
+  ///
+  /// # Arguments
+  ///
+  /// * `address` - The email address to notify
+  pub fn email(address: impl Into<String>) -> Self {
+    EscalationTarget::Email(address.into())
+  }
+
+  /// Creates a target that escalates to another escalation policy.
+  ///
+  /// # Arguments
+  ///
+  /// * `slug` - The slug of the escalation policy
+  pub fn policy(slug: impl Into<String>) -> Self {
+    EscalationTarget::Policy(slug.into())
+  }
+}
+
+impl From<EscalationTarget> for EscalationPolicyStepEntry {
+  fn from(target: EscalationTarget) -> Self {
+    let field = |key: &str, value: String| Some(BTreeMap::from([(key.to_string(), value)]));
+    let mut entry = EscalationPolicyStepEntry {
+      execution_type: None,
+      user: None,
+      rotation_group: None,
+      webhook: None,
+      email: None,
+      target_policy: None,
+    };
+
+    match target {
+      EscalationTarget::User(username) => {
+        entry.execution_type = Some("user".to_string());
+        entry.user = field("username", username);
+      }
+      EscalationTarget::RotationGroup(slug) => {
+        entry.execution_type = Some("rotation_group".to_string());
+        entry.rotation_group = field("slug", slug);
+      }
+      EscalationTarget::Webhook(slug) => {
+        entry.execution_type = Some("webhook".to_string());
+        entry.webhook = field("slug", slug);
+      }
+      EscalationTarget::Email(address) => {
+        entry.execution_type = Some("email".to_string());
+        entry.email = field("address", address);
+      }
+      EscalationTarget::Policy(slug) => {
+        entry.execution_type = Some("policy_routing".to_string());
+        entry.target_policy = field("policySlug", slug);
+      }
+    }
+
+    entry
+  }
+}
+
 /// Represents escalation policy details in a list response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct EscalationPolicyListDetail {
   /// The name of the escalation policy.
   pub name: String,
@@ -454,6 +2185,8 @@ pub struct EscalationPolicyListDetail {
 
 /// Represents an element in an escalation policy list.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct EscalationPolicyListElement {
   /// The escalation policy details.
   pub policy: EscalationPolicyListDetail,
@@ -463,61 +2196,398 @@ pub struct EscalationPolicyListElement {
 
 /// Response containing a list of escalation policies.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct EscalationPolicyList {
   /// The list of escalation policies.
   pub policies: Vec<EscalationPolicyListElement>,
 }
 
-/// Represents a routing key for directing alerts.
+impl_list_response!(EscalationPolicyList, policies, EscalationPolicyListElement);
+
+/// Where a chat message is posted on the timeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatTarget {
+  /// Post to the timeline of incidents for a routing key.
+  RoutingKey(String),
+  /// Post to a team's timeline.
+  Team(String),
+}
+
+/// A chat message posted to the VictorOps timeline.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RoutingKey {
-  /// The routing key value used to route alerts.
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ChatMessage {
+  /// The text of the message.
+  pub text: String,
+  /// The routing key whose timeline receives the message.
   #[serde(skip_serializing_if = "Option::is_none", rename = "routingKey")]
   pub routing_key: Option<String>,
-  /// The list of targets that this routing key routes to.
-  #[serde(default, skip_serializing_if = "Vec::is_empty")]
-  pub targets: Vec<String>,
+  /// The slug of the team whose timeline receives the message.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub team: Option<String>,
+}
+
+impl ChatMessage {
+  /// Creates a chat message for the given target.
+  ///
+  /// # Arguments
+  ///
+  /// * `text` - The text of the message
+  /// * `target` - The routing key or team whose timeline receives the message
+  pub fn new(text: &str, target: &ChatTarget) -> Self {
+    let (routing_key, team) = match target {
+      ChatTarget::RoutingKey(key) => (Some(key.clone()), None),
+      ChatTarget::Team(slug) => (None, Some(slug.clone())),
+    };
+
+    ChatMessage {
+      text: text.to_string(),
+      routing_key,
+      team,
+    }
+  }
 }
 
-/// Represents targets in a routing key response.
+/// The current maintenance mode state for the organization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RoutingKeyResponseTargets {
-  /// The slug of the escalation policy this routing key targets.
-  #[serde(skip_serializing_if = "Option::is_none", rename = "policySlug")]
-  pub policy_slug: Option<String>,
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct MaintenanceModeState {
+  /// The maintenance mode instances currently in effect.
+  #[serde(default, rename = "activeInstances")]
+  pub active_instances: Vec<MaintenanceModeInstance>,
 }
 
-/// Response containing routing key information.
+impl MaintenanceModeState {
+  /// Returns true if any maintenance mode instance is in effect.
+  pub fn is_active(&self) -> bool {
+    !self.active_instances.is_empty()
+  }
+
+  /// Returns true if a global maintenance mode instance is muting all alerting.
+  pub fn is_global(&self) -> bool {
+    self.active_instances.iter().any(|i| i.is_global)
+  }
+}
+
+/// A single maintenance mode instance.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RoutingKeyResponse {
-  /// The routing key value.
-  #[serde(skip_serializing_if = "Option::is_none", rename = "routingKey")]
-  pub routing_key: Option<String>,
-  /// The targets that this routing key routes to.
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct MaintenanceModeInstance {
+  /// The identifier of the instance.
+  #[serde(rename = "instanceId")]
+  pub instance_id: String,
+  /// When the instance was started, in milliseconds since the Unix epoch.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "startedAt")]
+  pub started_at: Option<i64>,
+  /// The username of the user who started the instance.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "startedBy")]
+  pub started_by: Option<String>,
+  /// The reason maintenance mode was started.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// Whether the instance mutes alerting for the whole organization.
+  #[serde(default, rename = "isGlobal")]
+  pub is_global: bool,
+  /// The routing keys muted by the instance.
   #[serde(default, skip_serializing_if = "Vec::is_empty")]
-  pub targets: Vec<RoutingKeyResponseTargets>,
+  pub targets: Vec<MaintenanceModeTarget>,
 }
 
-/// Response containing a list of routing keys.
+/// A set of entities muted by a maintenance mode instance.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RoutingKeyResponseList {
-  /// The list of routing keys.
-  #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "routingKeys")]
-  pub routing_keys: Vec<RoutingKeyResponse>,
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MaintenanceModeTarget {
+  /// The kind of entity muted, such as `RoutingKeys`.
+  #[serde(rename = "type")]
+  pub target_type: String,
+  /// The names of the muted entities.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub names: Vec<String>,
 }
 
-/// Types of contact methods available in VictorOps.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ContactType {
-  /// Phone or SMS contact method.
-  Phone,
-  /// Email contact method.
-  Email,
-  /// Mobile device push notification contact method.
-  Device,
+/// Request to start maintenance mode.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StartMaintenanceModeRequest {
+  /// The reason maintenance mode is being started.
+  pub purpose: String,
+  /// Whether to mute alerting for the whole organization.
+  #[serde(rename = "isGlobal")]
+  pub is_global: bool,
+  /// The routing keys to mute when not global.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub targets: Vec<MaintenanceModeTarget>,
 }
 
-impl ContactType {
+/// Response from starting maintenance mode.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct StartMaintenanceModeResponse {
+  /// The identifier of the created maintenance mode instance.
+  #[serde(rename = "instanceId")]
+  pub instance_id: String,
+}
+
+/// Represents a routing key for directing alerts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RoutingKey {
+  /// The routing key value used to route alerts.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "routingKey")]
+  pub routing_key: Option<String>,
+  /// The list of targets that this routing key routes to.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub targets: Vec<RoutingTarget>,
+}
+
+impl RoutingKey {
+  /// Returns a builder that validates the routing key before constructing it.
+  pub fn builder() -> RoutingKeyBuilder {
+    RoutingKeyBuilder::default()
+  }
+}
+
+impl From<RoutingKeyResponse> for RoutingKey {
+  /// Converts a fetched routing key into one that can be sent back to the API.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # async fn run(client: victorops::Client) -> victorops::ApiResult<()> {
+  /// use victorops::{RoutingKey, RoutingTarget};
+  ///
+  /// if let (Some(existing), _) = client.get_routing_key("database").await? {
+  ///   let mut key = RoutingKey::from(existing);
+  ///   key.targets.push(RoutingTarget::policy("pol-secondary"));
+  ///   client.create_routing_key(&key).await?;
+  /// }
+  /// # Ok(())
+  /// # }
+  /// ```
+  fn from(response: RoutingKeyResponse) -> Self {
+    RoutingKey {
+      routing_key: response.routing_key,
+      targets: response.targets,
+    }
+  }
+}
+
+/// The slug identifying an escalation policy, such as `pol-abc123`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct PolicySlug(String);
+
+impl PolicySlug {
+  /// Creates a policy slug.
+  pub fn new(slug: impl Into<String>) -> Self {
+    PolicySlug(slug.into())
+  }
+
+  /// Returns the slug as a string slice.
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
+
+impl From<&str> for PolicySlug {
+  fn from(slug: &str) -> Self {
+    PolicySlug::new(slug)
+  }
+}
+
+impl From<String> for PolicySlug {
+  fn from(slug: String) -> Self {
+    PolicySlug(slug)
+  }
+}
+
+impl AsRef<str> for PolicySlug {
+  fn as_ref(&self) -> &str {
+    &self.0
+  }
+}
+
+impl std::fmt::Display for PolicySlug {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+/// Where a routing key sends the alerts it receives.
+///
+/// Requests list targets as bare policy slugs, while responses wrap each in
+/// a `{"policySlug": ...}` object. Both shapes deserialize into the same
+/// value, and targets always serialize as slugs, so a fetched key can be
+/// sent back unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RoutingTarget {
+  /// An escalation policy, identified by its slug.
+  Policy(PolicySlug),
+}
+
+impl RoutingTarget {
+  /// Creates a target for the escalation policy with the given slug.
+  pub fn policy(slug: impl Into<PolicySlug>) -> Self {
+    RoutingTarget::Policy(slug.into())
+  }
+
+  /// Returns the slug of the targeted escalation policy.
+  pub fn policy_slug(&self) -> Option<&PolicySlug> {
+    match self {
+      RoutingTarget::Policy(slug) => Some(slug),
+    }
+  }
+}
+
+impl Serialize for RoutingTarget {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match self {
+      RoutingTarget::Policy(slug) => slug.serialize(serializer),
+    }
+  }
+}
+
+impl<'de> Deserialize<'de> for RoutingTarget {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Wire {
+      Slug(PolicySlug),
+      Object {
+        #[serde(rename = "policySlug")]
+        policy_slug: PolicySlug,
+      },
+    }
+
+    Ok(match Wire::deserialize(deserializer)? {
+      Wire::Slug(slug) | Wire::Object { policy_slug: slug } => RoutingTarget::Policy(slug),
+    })
+  }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for RoutingTarget {
+  fn schema_name() -> Cow<'static, str> {
+    "RoutingTarget".into()
+  }
+
+  fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+      "anyOf": [
+        { "type": "string" },
+        {
+          "type": "object",
+          "properties": { "policySlug": { "type": "string" } },
+          "required": ["policySlug"]
+        }
+      ]
+    })
+  }
+}
+
+/// Builds a [`RoutingKey`], validating it against VictorOps naming rules.
+#[derive(Debug, Clone, Default)]
+pub struct RoutingKeyBuilder {
+  routing_key: Option<String>,
+  targets: Vec<RoutingTarget>,
+}
+
+impl RoutingKeyBuilder {
+  /// Sets the routing key value.
+  ///
+  /// # Arguments
+  ///
+  /// * `routing_key` - The routing key used to route alerts
+  pub fn routing_key(mut self, routing_key: &str) -> Self {
+    self.routing_key = Some(routing_key.to_string());
+    self
+  }
+
+  /// Adds an escalation policy the routing key routes to.
+  ///
+  /// # Arguments
+  ///
+  /// * `policy_slug` - The slug of the escalation policy to target
+  pub fn target(mut self, policy_slug: &str) -> Self {
+    self.targets.push(RoutingTarget::policy(policy_slug));
+    self
+  }
+
+  /// Validates the routing key and constructs it.
+  ///
+  /// Routing keys may only contain ASCII letters, digits, `-`, `_`, and `.`,
+  /// and must route to at least one escalation policy.
+  ///
+  /// # Returns
+  ///
+  /// The routing key, or `Error::InvalidInput` if it fails validation.
+  pub fn build(self) -> ApiResult<RoutingKey> {
+    let routing_key = self
+      .routing_key
+      .ok_or_else(|| Error::InvalidInput("routing key is required".to_string()))?;
+
+    if let Some(problem) = routing_key_problem(&routing_key) {
+      return Err(Error::InvalidInput(format!("routing key {}", problem)));
+    }
+
+    if self.targets.is_empty() {
+      return Err(Error::InvalidInput(format!(
+        "routing key {:?} must have at least one target",
+        routing_key
+      )));
+    }
+
+    Ok(RoutingKey {
+      routing_key: Some(routing_key),
+      targets: self.targets,
+    })
+  }
+}
+
+/// Response containing routing key information.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RoutingKeyResponse {
+  /// The routing key value.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "routingKey")]
+  pub routing_key: Option<String>,
+  /// The targets that this routing key routes to.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub targets: Vec<RoutingTarget>,
+}
+
+/// Response containing a list of routing keys.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RoutingKeyResponseList {
+  /// The list of routing keys.
+  #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "routingKeys")]
+  pub routing_keys: Vec<RoutingKeyResponse>,
+}
+
+impl_list_response!(RoutingKeyResponseList, routing_keys, RoutingKeyResponse);
+
+/// Types of contact methods available in VictorOps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContactType {
+  /// Phone or SMS contact method.
+  Phone,
+  /// Email contact method.
+  Email,
+  /// Mobile device push notification contact method.
+  Device,
+}
+
+impl ContactType {
   /// Returns the endpoint noun for this contact type.
   pub fn endpoint_noun(&self) -> &'static str {
     match self {
@@ -539,7 +2609,9 @@ impl ContactType {
 }
 
 /// Represents a contact method for a user.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Contact {
   /// The phone number for phone-based contact methods.
   #[serde(skip_serializing_if = "Option::is_none", rename = "phone")]
@@ -557,20 +2629,250 @@ pub struct Contact {
   #[serde(skip_serializing_if = "Option::is_none", rename = "extId")]
   pub ext_id: Option<String>,
   /// The unique identifier of this contact method.
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub id: Option<i32>,
-  /// The value of this contact method.
+  #[serde(
+    default,
+    skip_serializing_if = "Option::is_none",
+    deserialize_with = "deserialize_optional_contact_id"
+  )]
+  #[cfg_attr(feature = "schemars", schemars(with = "Option<i64>"))]
+  pub id: Option<i64>,
+  /// The value of this contact method. For mobile devices this is the
+  /// device identifier registered by the VictorOps app.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub value: Option<String>,
   /// The verification status of this contact method.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub verified: Option<String>,
+  pub verified: Option<VerificationStatus>,
+  /// The kind of contact method.
+  ///
+  /// Contact JSON carries no type, so this is set by the constructors and by
+  /// the client from the endpoint group a contact was fetched from. It is
+  /// never sent to the API.
+  #[serde(skip)]
+  pub kind: Option<ContactType>,
+}
+
+/// Whether a contact method has been verified.
+///
+/// The API reports verification as a string or a boolean. Known values are
+/// matched case-insensitively; anything else is preserved in
+/// [`VerificationStatus::Unknown`] so it round-trips unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VerificationStatus {
+  /// The contact method has been verified.
+  Verified,
+  /// The contact method has not been verified.
+  Unverified,
+  /// A verification request has been sent but not yet confirmed.
+  Pending,
+  /// A verification status not known to this client.
+  Unknown(String),
+}
+
+impl VerificationStatus {
+  /// Returns the wire representation of this verification status.
+  pub fn as_str(&self) -> &str {
+    match self {
+      VerificationStatus::Verified => "verified",
+      VerificationStatus::Unverified => "unverified",
+      VerificationStatus::Pending => "pending",
+      VerificationStatus::Unknown(value) => value,
+    }
+  }
+
+  /// Returns whether the contact method has been verified.
+  pub fn is_verified(&self) -> bool {
+    *self == VerificationStatus::Verified
+  }
+}
+
+impl From<&str> for VerificationStatus {
+  fn from(value: &str) -> Self {
+    match value.trim().to_ascii_lowercase().as_str() {
+      "verified" | "true" | "yes" => VerificationStatus::Verified,
+      "unverified" | "not_verified" | "false" | "no" => VerificationStatus::Unverified,
+      "pending" | "sent" => VerificationStatus::Pending,
+      _ => VerificationStatus::Unknown(value.to_string()),
+    }
+  }
+}
+
+impl From<bool> for VerificationStatus {
+  fn from(verified: bool) -> Self {
+    if verified {
+      VerificationStatus::Verified
+    } else {
+      VerificationStatus::Unverified
+    }
+  }
+}
+
+impl Serialize for VerificationStatus {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(self.as_str())
+  }
+}
+
+impl<'de> Deserialize<'de> for VerificationStatus {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Wire {
+      Bool(bool),
+      Text(String),
+    }
+
+    Ok(match Wire::deserialize(deserializer)? {
+      Wire::Bool(verified) => VerificationStatus::from(verified),
+      Wire::Text(value) => VerificationStatus::from(value.as_str()),
+    })
+  }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for VerificationStatus {
+  fn schema_name() -> Cow<'static, str> {
+    "VerificationStatus".into()
+  }
+
+  fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+      "type": ["string", "boolean"],
+      "examples": ["verified", "unverified", "pending"]
+    })
+  }
 }
 
 impl Contact {
-  /// Determines the contact type based on the contact's fields.
+  /// Creates an email contact method.
+  ///
+  /// # Arguments
+  ///
+  /// * `address` - The email address
+  /// * `label` - The label shown for the contact method
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use victorops::{Contact, ContactType};
+  ///
+  /// let contact = Contact::email("jdoe@example.com", "Work");
+  /// assert_eq!(contact.contact_type(), Some(ContactType::Email));
+  /// ```
+  pub fn email(address: impl Into<String>, label: impl Into<String>) -> Self {
+    Contact {
+      email: Some(address.into()),
+      kind: Some(ContactType::Email),
+      ..Contact::labelled(label)
+    }
+  }
+
+  /// Creates a phone contact method.
+  ///
+  /// # Arguments
+  ///
+  /// * `number` - The phone number in E.164 format, such as `+15555550123`
+  /// * `label` - The label shown for the contact method
+  pub fn phone(number: impl Into<String>, label: impl Into<String>) -> Self {
+    Contact {
+      phone_number: Some(number.into()),
+      kind: Some(ContactType::Phone),
+      ..Contact::labelled(label)
+    }
+  }
+
+  /// Creates a mobile device contact method.
+  ///
+  /// # Arguments
+  ///
+  /// * `value` - The device name registered by the VictorOps mobile app
+  /// * `label` - The label shown for the contact method
+  pub fn device(value: impl Into<String>, label: impl Into<String>) -> Self {
+    Contact {
+      value: Some(value.into()),
+      kind: Some(ContactType::Device),
+      ..Contact::labelled(label)
+    }
+  }
+
+  /// The contact method ID VictorOps uses for "All Devices".
+  ///
+  /// Paging policies can notify every device a user has registered through
+  /// this ID, but the devices endpoint does not list it.
+  pub const ALL_DEVICES_ID: i64 = 0;
+
+  /// Creates the "All Devices" contact method, which notifies every mobile
+  /// device registered to a user.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use victorops::{Contact, ContactType};
+  ///
+  /// let contact = Contact::all_devices();
+  /// assert!(contact.is_all_devices());
+  /// assert_eq!(contact.contact_type(), Some(ContactType::Device));
+  /// ```
+  pub fn all_devices() -> Self {
+    Contact {
+      rank: Some(0),
+      id: Some(Contact::ALL_DEVICES_ID),
+      value: Some("All Devices".to_string()),
+      kind: Some(ContactType::Device),
+      ..Contact::labelled("All Devices")
+    }
+  }
+
+  /// Returns true if this is the "All Devices" contact method.
+  pub fn is_all_devices(&self) -> bool {
+    self.contact_type() == Some(ContactType::Device) && self.id == Some(Contact::ALL_DEVICES_ID)
+  }
+
+  /// Returns the device identifier for mobile device contact methods.
+  ///
+  /// # Returns
+  ///
+  /// The `value` of a device contact, or `None` for phones and emails.
+  pub fn device_id(&self) -> Option<&str> {
+    match self.contact_type() {
+      Some(ContactType::Device) => self.value.as_deref(),
+      _ => None,
+    }
+  }
+
+  fn labelled(label: impl Into<String>) -> Self {
+    Contact {
+      phone_number: None,
+      email: None,
+      label: Some(label.into()),
+      rank: None,
+      ext_id: None,
+      id: None,
+      value: None,
+      verified: None,
+      kind: None,
+    }
+  }
+
+  /// Sets the kind of contact method.
+  pub(crate) fn with_kind(self, kind: ContactType) -> Self {
+    Contact {
+      kind: Some(kind),
+      ..self
+    }
+  }
+
+  /// Returns the kind of contact method.
+  ///
+  /// # Returns
+  ///
+  /// The contact's [`kind`](Contact::kind) if it is known, otherwise `Phone`
+  /// or `Email` when the matching field is set. Device contacts are only
+  /// recognized when their kind is known.
   pub fn contact_type(&self) -> Option<ContactType> {
-    if self.phone_number.is_some() {
+    if self.kind.is_some() {
+      self.kind
+    } else if self.phone_number.is_some() {
       Some(ContactType::Phone)
     } else if self.email.is_some() {
       Some(ContactType::Email)
@@ -582,14 +2884,30 @@ impl Contact {
 
 /// A group of contact methods.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct ContactGroup {
   /// The list of contact methods in this group.
   #[serde(rename = "contactMethods")]
   pub contact_methods: Vec<Contact>,
 }
 
+impl ContactGroup {
+  fn with_kind(self, kind: ContactType) -> Self {
+    ContactGroup {
+      contact_methods: self
+        .contact_methods
+        .into_iter()
+        .map(|contact| contact.with_kind(kind))
+        .collect(),
+    }
+  }
+}
+
 /// Response containing all contact methods for a user.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct AllContactResponse {
   /// The phone contact methods for the user.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -602,8 +2920,21 @@ pub struct AllContactResponse {
   pub devices: Option<ContactGroup>,
 }
 
+impl AllContactResponse {
+  /// Sets the kind of every contact from the group it was listed in.
+  pub(crate) fn with_kinds(self) -> Self {
+    AllContactResponse {
+      phones: self.phones.map(|g| g.with_kind(ContactType::Phone)),
+      emails: self.emails.map(|g| g.with_kind(ContactType::Email)),
+      devices: self.devices.map(|g| g.with_kind(ContactType::Device)),
+    }
+  }
+}
+
 /// Response for getting all contacts of a specific type.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct GetAllContactResponse {
   /// The list of contact methods of the requested type.
   #[serde(
@@ -618,6 +2949,272 @@ pub struct GetAllContactResponse {
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_severity_from_state() {
+    assert_eq!(Severity::from_state("CRITICAL"), Severity::Critical);
+    assert_eq!(Severity::from_state("critical"), Severity::Critical);
+    assert_eq!(Severity::from_state("WARNING"), Severity::Warning);
+    assert_eq!(Severity::from_state("INFO"), Severity::Info);
+    assert_eq!(Severity::from_state("OK"), Severity::Info);
+    assert_eq!(Severity::from_state("RECOVERY"), Severity::Info);
+    assert_eq!(Severity::from_state("ACKNOWLEDGEMENT"), Severity::Info);
+    assert_eq!(Severity::from_state("bogus"), Severity::Unknown);
+  }
+
+  #[test]
+  fn test_severity_table() {
+    let incident = |state: &str| -> Incident {
+      serde_json::from_value(serde_json::json!({ "entityState": state })).unwrap()
+    };
+
+    let default = SeverityTable::new();
+    assert_eq!(default.incident_priority(&incident("CRITICAL")), "P1");
+    assert_eq!(default.incident_priority(&incident("DEGRADED")), "P4");
+
+    let table = SeverityTable::new()
+      .state("degraded", Severity::Warning)
+      .state("WARNING", Severity::Critical)
+      .priority(Severity::Critical, "SEV1");
+    assert_eq!(table.classify(&incident("Degraded")), Severity::Warning);
+    assert_eq!(table.incident_priority(&incident("WARNING")), "SEV1");
+    assert_eq!(table.incident_priority(&incident("INFO")), "P3");
+    assert_eq!(
+      table.classify(&serde_json::from_str("{}").unwrap()),
+      Severity::Unknown
+    );
+  }
+
+  #[test]
+  fn test_request_wire_payloads() {
+    let incident = CreateIncidentRequest {
+      summary: "Database down".to_string(),
+      details: String::new(),
+      user_name: "jdoe".to_string(),
+      targets: vec![IncidentTarget::user("alice")],
+      is_multi_responder: false,
+    };
+    assert_eq!(
+      serde_json::to_value(&incident).unwrap(),
+      serde_json::json!({
+        "summary": "Database down",
+        "details": "",
+        "userName": "jdoe",
+        "targets": [{"type": "User", "slug": "alice"}],
+        "isMultiResponder": false
+      })
+    );
+
+    assert_eq!(
+      serde_json::to_value(IncidentActionRequest::new("jdoe", &[7], None)).unwrap(),
+      serde_json::json!({"userName": "jdoe", "incidentNames": ["7"]})
+    );
+
+    let global = StartMaintenanceModeRequest {
+      purpose: "Upgrade".to_string(),
+      is_global: true,
+      targets: Vec::new(),
+    };
+    assert_eq!(
+      serde_json::to_value(&global).unwrap(),
+      serde_json::json!({"purpose": "Upgrade", "isGlobal": true})
+    );
+
+    let target = MaintenanceModeTarget {
+      target_type: "RoutingKeys".to_string(),
+      names: Vec::new(),
+    };
+    assert_eq!(
+      serde_json::to_value(&target).unwrap(),
+      serde_json::json!({"type": "RoutingKeys"})
+    );
+
+    let key = RoutingKey {
+      routing_key: Some("database".to_string()),
+      targets: Vec::new(),
+    };
+    assert_eq!(
+      serde_json::to_value(&key).unwrap(),
+      serde_json::json!({"routingKey": "database"})
+    );
+  }
+
+  #[test]
+  fn test_incident_number_i64() {
+    let incident = |number: serde_json::Value| -> Incident {
+      serde_json::from_value(serde_json::json!({ "incidentNumber": number })).unwrap()
+    };
+
+    assert_eq!(
+      incident(serde_json::json!("1234")).incident_number_i64(),
+      Some(1234)
+    );
+    assert_eq!(
+      incident(serde_json::json!(" #42 ")).incident_number_i64(),
+      Some(42)
+    );
+    assert_eq!(
+      incident(serde_json::json!("INC-7")).incident_number_i64(),
+      None
+    );
+    assert_eq!(
+      incident(serde_json::Value::Null).incident_number_i64(),
+      None
+    );
+  }
+
+  #[test]
+  fn test_incident_response_grouping_and_sorting() {
+    let mut response: IncidentResponse = serde_json::from_value(serde_json::json!({
+      "incidents": [
+        {"incidentNumber": "1", "currentPhase": "ACKED", "startTime": "2026-01-01T00:00:00Z", "pagedTeams": ["team-db"]},
+        {"incidentNumber": "2", "currentPhase": "UNACKED", "pagedTeams": ["team-ops"]},
+        {"incidentNumber": "3", "currentPhase": "UNACKED", "startTime": "2026-01-03T00:00:00Z", "pagedTeams": ["team-db", "team-ops"]},
+        {"incidentNumber": "4", "currentPhase": "UNACKED", "startTime": "2026-01-02T00:00:00Z"}
+      ]
+    }))
+    .unwrap();
+
+    let number = |i: &Incident| i.incident_number.clone().unwrap();
+    let groups = response.group_by_team();
+    assert_eq!(
+      groups["team-db"]
+        .iter()
+        .map(|i| number(i))
+        .collect::<Vec<_>>(),
+      ["1", "3"]
+    );
+    assert_eq!(groups["team-ops"].len(), 2);
+    assert_eq!(groups.len(), 2);
+
+    assert_eq!(response.oldest_unacked().map(number).as_deref(), Some("4"));
+    assert_eq!(response.incident_numbers(), [1, 2, 3, 4]);
+
+    response.sort_by_start_time();
+    assert_eq!(
+      response.iter().map(number).collect::<Vec<_>>(),
+      ["1", "4", "3", "2"]
+    );
+  }
+
+  #[test]
+  fn test_incident_severity_sorting() {
+    let mut incidents: Vec<Incident> = ["INFO", "CRITICAL", "", "WARNING"]
+      .iter()
+      .map(|state| serde_json::from_value(serde_json::json!({ "entityState": state })).unwrap())
+      .collect();
+    incidents.push(serde_json::from_str("{}").unwrap());
+
+    incidents.sort_by_key(Incident::severity);
+
+    let severities: Vec<Severity> = incidents.iter().map(Incident::severity).collect();
+    assert_eq!(
+      severities,
+      vec![
+        Severity::Critical,
+        Severity::Warning,
+        Severity::Info,
+        Severity::Unknown,
+        Severity::Unknown,
+      ]
+    );
+  }
+
+  #[test]
+  fn test_on_call_type_serde() {
+    let entry: ApiOnCallEntry =
+      serde_json::from_str(r#"{"onCallType": "rotation_group"}"#).unwrap();
+    assert_eq!(entry.on_call_type, Some(OnCallType::Rotation));
+
+    let entry: ApiOnCallEntry = serde_json::from_str(r#"{"onCallType": "Override"}"#).unwrap();
+    assert_eq!(entry.on_call_type, Some(OnCallType::Override));
+
+    let entry: ApiOnCallEntry = serde_json::from_str(r#"{"onCallType": "shadow"}"#).unwrap();
+    assert_eq!(
+      entry.on_call_type,
+      Some(OnCallType::Unknown("shadow".to_string()))
+    );
+
+    let json = serde_json::to_string(&entry).unwrap();
+    assert_eq!(json, r#"{"onCallType":"shadow"}"#);
+    assert_eq!(
+      serde_json::to_string(&OnCallType::User).unwrap(),
+      r#""user""#
+    );
+  }
+
+  fn override_schedule() -> ApiTeamSchedule {
+    serde_json::from_str(
+      r#"{
+        "schedules": [
+          {
+            "policy": {"slug": "pol-1"},
+            "overrides": [
+              {
+                "origOnCallUser": {"username": "alice"},
+                "overrideOnCallUser": {"username": "bob"},
+                "start": "2026-01-01T00:00:00Z",
+                "end": "2026-01-02T00:00:00Z"
+              },
+              {
+                "origOnCallUser": {"username": "carol"},
+                "overrideOnCallUser": {"username": "Alice"},
+                "start": "2026-01-05T00:00:00Z",
+                "end": "2026-01-06T00:00:00Z"
+              }
+            ]
+          },
+          {
+            "policy": {"slug": "pol-2"},
+            "overrides": [
+              {
+                "origOnCallUser": {"username": "dave"},
+                "overrideOnCallUser": {"username": "erin"},
+                "start": "2026-01-01T12:00:00Z"
+              }
+            ]
+          }
+        ]
+      }"#,
+    )
+    .unwrap()
+  }
+
+  #[test]
+  #[cfg(feature = "chrono")]
+  fn test_active_overrides_at() {
+    let schedule = override_schedule();
+    let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+
+    let active = schedule.active_overrides_at(at("2026-01-01T12:00:00Z"));
+    assert_eq!(active.len(), 1);
+    assert!(active[0].is_override_user("bob"));
+
+    assert!(
+      schedule
+        .active_overrides_at(at("2026-01-02T00:00:00Z"))
+        .is_empty()
+    );
+    assert_eq!(
+      schedule.schedules[0]
+        .active_overrides_at(at("2026-01-05T00:00:00Z"))
+        .len(),
+      1
+    );
+  }
+
+  #[test]
+  fn test_overrides_for_user() {
+    let schedule = override_schedule();
+
+    let overrides = schedule.overrides_for_user("alice");
+    assert_eq!(overrides.len(), 2);
+    assert!(overrides[0].is_original_user("alice"));
+    assert!(overrides[1].is_override_user("alice"));
+
+    assert_eq!(schedule.overrides_for_user("erin").len(), 1);
+    assert!(schedule.overrides_for_user("nobody").is_empty());
+  }
+
   #[test]
   fn test_contact_type_endpoint_noun() {
     assert_eq!(ContactType::Phone.endpoint_noun(), "phones");
@@ -657,6 +3254,7 @@ mod tests {
       id: None,
       value: None,
       verified: None,
+      kind: None,
     };
     assert_eq!(phone_contact.contact_type(), Some(ContactType::Phone));
 
@@ -669,6 +3267,7 @@ mod tests {
       id: None,
       value: None,
       verified: None,
+      kind: None,
     };
     assert_eq!(email_contact.contact_type(), Some(ContactType::Email));
 
@@ -681,10 +3280,43 @@ mod tests {
       id: None,
       value: None,
       verified: None,
+      kind: None,
     };
     assert_eq!(empty_contact.contact_type(), None);
   }
 
+  #[test]
+  fn test_contact_constructors() {
+    let email = Contact::email("jdoe@example.com", "Work");
+    assert_eq!(email.contact_type(), Some(ContactType::Email));
+    assert_eq!(
+      serde_json::to_value(&email).unwrap(),
+      serde_json::json!({"email": "jdoe@example.com", "label": "Work"})
+    );
+
+    let phone = Contact::phone("+15555550123", "Mobile");
+    assert_eq!(phone.contact_type(), Some(ContactType::Phone));
+    assert_eq!(phone.label.as_deref(), Some("Mobile"));
+
+    let device = Contact::device("Pixel 8", "Phone app");
+    assert_eq!(device.contact_type(), Some(ContactType::Device));
+    assert!(device.phone_number.is_none() && device.email.is_none());
+    assert_eq!(device.device_id(), Some("Pixel 8"));
+    assert!(!device.is_all_devices());
+    assert_eq!(phone.device_id(), None);
+    assert_eq!(
+      serde_json::to_value(&device).unwrap(),
+      serde_json::json!({"value": "Pixel 8", "label": "Phone app"})
+    );
+
+    let untyped: Contact = serde_json::from_value(serde_json::json!({"value": "Pixel 8"})).unwrap();
+    assert_eq!(untyped.contact_type(), None);
+    assert_eq!(
+      untyped.with_kind(ContactType::Device).device_id(),
+      Some("Pixel 8")
+    );
+  }
+
   #[test]
   fn test_contact_serialization() {
     let contact = Contact {
@@ -695,11 +3327,13 @@ mod tests {
       ext_id: Some("ext123".to_string()),
       id: Some(42),
       value: Some("contact-value".to_string()),
-      verified: Some("true".to_string()),
+      verified: Some(VerificationStatus::Verified),
+      kind: None,
     };
 
     let json = serde_json::to_string(&contact).unwrap();
     let deserialized: Contact = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.verified, Some(VerificationStatus::Verified));
 
     assert_eq!(deserialized.phone_number, contact.phone_number);
     assert_eq!(deserialized.email, contact.email);
@@ -707,6 +3341,56 @@ mod tests {
     assert_eq!(deserialized.rank, contact.rank);
   }
 
+  #[test]
+  fn test_contact_ids_are_integers() {
+    let contact: Contact = serde_json::from_value(serde_json::json!({"id": 12345.0})).unwrap();
+    assert_eq!(contact.id, Some(12345));
+
+    let contact: Contact = serde_json::from_value(serde_json::json!({"id": "678"})).unwrap();
+    assert_eq!(contact.id, Some(678));
+
+    let contact: Contact = serde_json::from_value(serde_json::json!({"id": null})).unwrap();
+    assert_eq!(contact.id, None);
+
+    let method: ContactMethod =
+      serde_json::from_value(serde_json::json!({"id": 9007199254740993_i64, "label": "Default"}))
+        .unwrap();
+    assert_eq!(method.id, 9007199254740993);
+
+    assert!(serde_json::from_value::<Contact>(serde_json::json!({"id": 1.5})).is_err());
+  }
+
+  #[test]
+  fn test_verification_status_parsing() {
+    let status =
+      |value: serde_json::Value| -> VerificationStatus { serde_json::from_value(value).unwrap() };
+
+    assert_eq!(
+      status(serde_json::json!("VERIFIED")),
+      VerificationStatus::Verified
+    );
+    assert_eq!(
+      status(serde_json::json!("true")),
+      VerificationStatus::Verified
+    );
+    assert_eq!(
+      status(serde_json::json!(false)),
+      VerificationStatus::Unverified
+    );
+    assert_eq!(
+      status(serde_json::json!("Pending")),
+      VerificationStatus::Pending
+    );
+    assert!(status(serde_json::json!(true)).is_verified());
+
+    let unknown = status(serde_json::json!("EXPIRED"));
+    assert_eq!(unknown, VerificationStatus::Unknown("EXPIRED".to_string()));
+    assert_eq!(
+      serde_json::to_value(&unknown).unwrap(),
+      serde_json::json!("EXPIRED")
+    );
+  }
+
   #[test]
   fn test_contact_group_serialization() {
     let contact_group = ContactGroup {
@@ -719,6 +3403,7 @@ mod tests {
         id: None,
         value: None,
         verified: None,
+        kind: None,
       }],
     };
 
@@ -728,4 +3413,334 @@ mod tests {
     let deserialized: ContactGroup = serde_json::from_str(&json).unwrap();
     assert_eq!(deserialized.contact_methods.len(), 1);
   }
+
+  #[test]
+  fn test_user_builder() {
+    let user = User::builder("jdoe", "john.doe@example.com")
+      .first_name("John")
+      .last_name(String::from("Doe"))
+      .admin(true)
+      .expiration_hours(24)
+      .build()
+      .unwrap();
+    assert_eq!(user.username, Some("jdoe".to_string()));
+    assert_eq!(user.email, Some("john.doe@example.com".to_string()));
+    assert_eq!(user.first_name, Some("John".to_string()));
+    assert_eq!(user.admin, Some(true));
+    assert_eq!(user.expiration_hours, Some(24));
+    assert!(user.created_at.is_none());
+
+    let json =
+      serde_json::to_value(User::builder("jdoe", "j@example.com").build().unwrap()).unwrap();
+    assert_eq!(
+      json,
+      serde_json::json!({"username": "jdoe", "email": "j@example.com"})
+    );
+
+    let invalid = User::builder("", "not-an-email").build();
+    assert!(matches!(invalid, Err(Error::InvalidInput(_))));
+  }
+
+  #[test]
+  fn test_user_roles() {
+    let user: User =
+      serde_json::from_value(serde_json::json!({"username": "jdoe", "role": "alert_admin"}))
+        .unwrap();
+    assert_eq!(user.role, Some(UserRole::AlertAdmin));
+
+    for role in ["global_admin", "Alert Admin", "responder"] {
+      let user: User =
+        serde_json::from_value(serde_json::json!({"username": "jdoe", "role": role})).unwrap();
+      assert_eq!(
+        serde_json::to_value(&user).unwrap()["role"],
+        serde_json::json!(role)
+      );
+    }
+    assert_eq!(UserRole::from("global_admin"), UserRole::GlobalAdmin);
+    assert_eq!(
+      UserRole::from("Alert Admin"),
+      UserRole::Unknown("Alert Admin".to_string())
+    );
+
+    let user: User =
+      serde_json::from_value(serde_json::json!({"username": "jdoe", "admin": true})).unwrap();
+    assert_eq!(user.effective_role(), Some(UserRole::Admin));
+
+    let user: User =
+      serde_json::from_value(serde_json::json!({"username": "jdoe", "admin": false})).unwrap();
+    assert_eq!(user.effective_role(), None);
+  }
+
+  #[test]
+  fn test_user_representation_conversions() {
+    let admin: Admin = serde_json::from_value(serde_json::json!({
+      "username": "jdoe",
+      "firstName": "John",
+      "lastName": "Doe",
+      "_selfUrl": "/api-public/v1/user/jdoe"
+    }))
+    .unwrap();
+    let user = User::builder("jdoe", "john.doe@example.com")
+      .build()
+      .unwrap();
+
+    assert_eq!(ApiUser::from(&admin), ApiUser::new("jdoe"));
+    assert_eq!(ApiUser::from(&user), ApiUser::from(admin.clone()));
+
+    let from_admin = User::from(admin);
+    assert_eq!(from_admin.username.as_deref(), Some("jdoe"));
+    assert_eq!(from_admin.last_name.as_deref(), Some("Doe"));
+    assert!(from_admin.email.is_none());
+  }
+
+  #[test]
+  fn test_escalation_policy_builder() {
+    let policy = EscalationPolicy::builder("Database Primary", "team-db")
+      .step(0, [EscalationTarget::rotation_group("primary")])
+      .step(
+        15,
+        [
+          EscalationTarget::user("jdoe"),
+          EscalationTarget::policy("pol-dba"),
+        ],
+      )
+      .ignore_custom_paging_policies(true)
+      .build()
+      .unwrap();
+
+    assert_eq!(
+      serde_json::to_value(&policy).unwrap(),
+      serde_json::json!({
+        "name": "Database Primary",
+        "teamSlug": "team-db",
+        "ignoreCustomPagingPolicies": true,
+        "steps": [
+          {
+            "timeout": 0,
+            "entries": [{"executionType": "rotation_group", "rotationGroup": {"slug": "primary"}}]
+          },
+          {
+            "timeout": 15,
+            "entries": [
+              {"executionType": "user", "user": {"username": "jdoe"}},
+              {"executionType": "policy_routing", "targetPolicy": {"policySlug": "pol-dba"}}
+            ]
+          }
+        ]
+      })
+    );
+
+    let invalid = EscalationPolicy::builder("Database Primary", "team-db")
+      .step(0, [EscalationTarget::user("jdoe")])
+      .step(0, [EscalationTarget::email("dba@example.com")])
+      .build();
+    assert!(matches!(invalid, Err(Error::InvalidInput(_))));
+  }
+
+  #[test]
+  fn test_incident_summary() {
+    let incident: Incident = serde_json::from_value(serde_json::json!({
+      "incidentNumber": "1234",
+      "currentPhase": "UNACKED",
+      "host": "web-prod-01",
+      "entityDisplayName": "disk full",
+      "alertCount": 3,
+      "startTime": "2026-01-01T12:00:00Z"
+    }))
+    .unwrap();
+    assert!(
+      incident
+        .summary()
+        .starts_with("#1234 UNACKED web-prod-01: disk full (3 alerts")
+    );
+
+    #[cfg(feature = "chrono")]
+    assert_eq!(
+      incident.summary_at("2026-01-01T12:12:30Z".parse().unwrap()),
+      "#1234 UNACKED web-prod-01: disk full (3 alerts, 12m old)"
+    );
+
+    let incident: Incident = serde_json::from_value(serde_json::json!({
+      "incidentNumber": "7",
+      "currentPhase": "RESOLVED",
+      "entityId": "db-1/disk",
+      "alertCount": 1
+    }))
+    .unwrap();
+    assert_eq!(incident.to_string(), "#7 RESOLVED db-1/disk (1 alert)");
+  }
+
+  #[test]
+  fn test_configuration_types_compare_by_value() {
+    let desired = EscalationPolicy::builder("Database Primary", "team-db")
+      .step(0, [EscalationTarget::user("jdoe")])
+      .build()
+      .unwrap();
+    let actual: EscalationPolicy = serde_json::from_value(serde_json::json!({
+      "name": "Database Primary",
+      "teamSlug": "team-db",
+      "ignoreCustomPagingPolicies": false,
+      "steps": [{"timeout": 0, "entries": [{"executionType": "user", "user": {"username": "jdoe"}}]}]
+    }))
+    .unwrap();
+    assert_eq!(desired, actual);
+
+    let teams: std::collections::HashSet<Team> =
+      [Team::new("Ops"), Team::new("Ops"), Team::new("Database")]
+        .into_iter()
+        .collect();
+    assert_eq!(teams.len(), 2);
+    assert_ne!(
+      Contact::email("jdoe@example.com", "Work"),
+      Contact::email("jdoe@example.com", "Home")
+    );
+  }
+
+  #[test]
+  fn test_user_list_flattens_nested_users() {
+    let list: UserList = serde_json::from_value(serde_json::json!({
+      "users": [[{"username": "alice"}, {"username": "bob"}], [{"username": "carol"}]]
+    }))
+    .unwrap();
+
+    let usernames: Vec<_> = list.iter().filter_map(|u| u.username.as_deref()).collect();
+    assert_eq!(usernames, ["alice", "bob", "carol"]);
+    assert_eq!(list.into_flat().len(), 3);
+  }
+
+  #[test]
+  fn test_list_responses_iterate_directly() {
+    let members: TeamMembers = serde_json::from_value(serde_json::json!({
+      "members": [{"username": "alice"}, {"username": "bob"}]
+    }))
+    .unwrap();
+
+    let mut usernames = Vec::new();
+    for user in &members {
+      usernames.extend(user.username.clone());
+    }
+    assert_eq!(usernames, ["alice", "bob"]);
+    assert_eq!(members.iter().count(), 2);
+
+    let keys: RoutingKeyResponseList = serde_json::from_value(serde_json::json!({
+      "routingKeys": [{"routingKey": "database", "targets": []}]
+    }))
+    .unwrap();
+    let keys: Vec<RoutingKeyResponse> = keys.into_iter().collect();
+    assert_eq!(keys[0].routing_key.as_deref(), Some("database"));
+  }
+
+  #[test]
+  #[cfg(feature = "schemars")]
+  fn test_json_schema_uses_wire_names() {
+    let schema = serde_json::to_value(schemars::schema_for!(Incident)).unwrap();
+    let properties = &schema["properties"];
+    assert!(properties.get("incidentNumber").is_some());
+    assert!(properties.get("incident_number").is_none());
+
+    let schema = serde_json::to_value(schemars::schema_for!(ApiOnCallEntry)).unwrap();
+    assert_eq!(
+      schema["$defs"]["OnCallType"]["type"],
+      serde_json::json!("string")
+    );
+  }
+
+  #[test]
+  fn test_incident_timing() {
+    let incident: Incident = serde_json::from_value(serde_json::json!({
+      "startTime": "2026-01-01T12:00:00Z",
+      "transitions": [
+        {"Name": "triggered", "At": "2026-01-01T12:00:00Z"},
+        {"Name": "ACKED", "At": "2026-01-01T12:04:00Z", "By": "jdoe"},
+        {"Name": "RESOLVED", "At": "2026-01-01T13:00:00Z", "By": "asmith"}
+      ]
+    }))
+    .unwrap();
+
+    assert_eq!(incident.acked_by(), Some("jdoe"));
+    assert_eq!(incident.resolved_by(), Some("asmith"));
+
+    #[cfg(feature = "chrono")]
+    {
+      assert_eq!(incident.time_to_ack(), Some(chrono::Duration::minutes(4)));
+      assert_eq!(incident.time_to_resolve(), Some(chrono::Duration::hours(1)));
+      assert_eq!(incident.duration(), Some(chrono::Duration::hours(1)));
+    }
+
+    let open: Incident =
+      serde_json::from_value(serde_json::json!({"startTime": "2026-01-01T12:00:00Z"})).unwrap();
+    assert!(open.acked_by().is_none());
+    #[cfg(feature = "chrono")]
+    {
+      assert!(open.time_to_ack().is_none());
+      assert!(open.duration().unwrap() > chrono::Duration::zero());
+    }
+  }
+
+  #[test]
+  fn test_take_request_new() {
+    let request = TakeRequest::new("jdoe", "asmith").unwrap();
+    assert_eq!(
+      serde_json::to_value(&request).unwrap(),
+      serde_json::json!({"fromUser": "jdoe", "toUser": "asmith"})
+    );
+
+    assert!(matches!(
+      TakeRequest::new(" ", "asmith"),
+      Err(Error::InvalidInput(_))
+    ));
+  }
+
+  #[test]
+  fn test_routing_targets_round_trip() {
+    let response: RoutingKeyResponse = serde_json::from_value(serde_json::json!({
+      "routingKey": "database",
+      "targets": [{"policySlug": "pol-db", "_teamUrl": "/api-public/v1/team/team-db"}]
+    }))
+    .unwrap();
+    assert_eq!(
+      response.targets[0].policy_slug().map(PolicySlug::as_str),
+      Some("pol-db")
+    );
+
+    let mut key = RoutingKey::from(response);
+    key.targets.push(RoutingTarget::policy("pol-ops"));
+    assert_eq!(
+      serde_json::to_value(&key).unwrap(),
+      serde_json::json!({"routingKey": "database", "targets": ["pol-db", "pol-ops"]})
+    );
+
+    let sent: RoutingKey = serde_json::from_value(serde_json::to_value(&key).unwrap()).unwrap();
+    assert_eq!(sent, key);
+  }
+
+  #[test]
+  fn test_routing_key_builder() {
+    let key = RoutingKey::builder()
+      .routing_key("db-primary_01.prod")
+      .target("pol-db")
+      .target("pol-ops")
+      .build()
+      .unwrap();
+    assert_eq!(key.routing_key, Some("db-primary_01.prod".to_string()));
+    assert_eq!(
+      key.targets,
+      vec![
+        RoutingTarget::policy("pol-db"),
+        RoutingTarget::policy("pol-ops")
+      ]
+    );
+
+    let missing = RoutingKey::builder().target("pol-db").build();
+    assert!(matches!(missing, Err(Error::InvalidInput(_))));
+
+    let spaces = RoutingKey::builder()
+      .routing_key("db primary")
+      .target("pol-db")
+      .build();
+    assert!(matches!(spaces, Err(Error::InvalidInput(_))));
+
+    let no_targets = RoutingKey::builder().routing_key("db").build();
+    assert!(matches!(no_targets, Err(Error::InvalidInput(_))));
+  }
 }
@@ -0,0 +1,411 @@
+use crate::error::{ApiResult, Error};
+use crate::types::{
+  Contact, CreateIncidentRequest, EscalationPolicy, IncidentActionRequest, RoutingKey, TakeRequest,
+  Team, User,
+};
+
+/// Client-side checks run on a request type before it is sent.
+///
+/// The client validates users, teams, escalation policies, routing keys,
+/// contacts, incidents, incident actions, and on-call takes before sending
+/// them, so mistakes the API would reject with an opaque `400` are reported
+/// up front. Call `validate` directly to check input before making a request.
+pub trait Validate {
+  /// Checks the value against VictorOps rules.
+  ///
+  /// # Returns
+  ///
+  /// `Ok(())`, or `Error::InvalidInput` listing every invalid field as
+  /// `field: problem`, separated by `; `.
+  fn validate(&self) -> ApiResult<()>;
+}
+
+/// Collects field-level problems found while validating a value.
+#[derive(Debug, Default)]
+struct Violations(Vec<String>);
+
+impl Violations {
+  fn add(&mut self, field: impl AsRef<str>, problem: impl AsRef<str>) {
+    self
+      .0
+      .push(format!("{}: {}", field.as_ref(), problem.as_ref()));
+  }
+
+  fn require_non_empty(&mut self, field: &str, value: Option<&str>) {
+    match value {
+      None => self.add(field, "is required"),
+      Some(value) if value.trim().is_empty() => self.add(field, "must not be empty"),
+      Some(_) => {}
+    }
+  }
+
+  fn into_result(self) -> ApiResult<()> {
+    if self.0.is_empty() {
+      Ok(())
+    } else {
+      Err(Error::InvalidInput(self.0.join("; ")))
+    }
+  }
+}
+
+/// Returns a problem with a routing key name, if it breaks VictorOps naming rules.
+///
+/// Routing keys may only contain ASCII letters, digits, `-`, `_`, and `.`.
+pub(crate) fn routing_key_problem(routing_key: &str) -> Option<String> {
+  if routing_key.is_empty() {
+    return Some("must not be empty".to_string());
+  }
+
+  routing_key
+    .chars()
+    .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+    .map(|c| format!("{:?} contains invalid character {:?}", routing_key, c))
+}
+
+/// Returns true if `phone` is an E.164 number, such as `+15555550123`.
+fn is_e164(phone: &str) -> bool {
+  let Some(digits) = phone.strip_prefix('+') else {
+    return false;
+  };
+
+  (2..=15).contains(&digits.len())
+    && !digits.starts_with('0')
+    && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Strips the spaces, dashes, dots, and parentheses people use to format phone numbers.
+pub(crate) fn normalize_phone(phone: &str) -> String {
+  phone
+    .chars()
+    .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+    .collect()
+}
+
+/// Returns true if `email` is a plain RFC 5322 address such as `jdoe@example.com`.
+///
+/// The local part may use the unquoted characters RFC 5322 allows, with dots
+/// only between other characters. The domain must have at least two labels of
+/// letters, digits, and inner hyphens. Quoted local parts and IP-literal
+/// domains are not accepted.
+fn is_email(email: &str) -> bool {
+  let Some((local, domain)) = email.rsplit_once('@') else {
+    return false;
+  };
+
+  let local_ok = (1..=64).contains(&local.len())
+    && local.split('.').all(|atom| {
+      !atom.is_empty()
+        && atom
+          .chars()
+          .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c))
+    });
+
+  let labels: Vec<&str> = domain.split('.').collect();
+  let domain_ok = domain.len() <= 253
+    && labels.len() >= 2
+    && labels.iter().all(|label| {
+      (1..=63).contains(&label.len())
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    });
+
+  local_ok && domain_ok && email.len() <= 254
+}
+
+fn check_email(violations: &mut Violations, field: &str, email: Option<&str>) {
+  if let Some(email) = email
+    && !is_email(email)
+  {
+    violations.add(field, format!("{:?} is not an email address", email));
+  }
+}
+
+impl Validate for User {
+  fn validate(&self) -> ApiResult<()> {
+    let mut violations = Violations::default();
+    violations.require_non_empty("username", self.username.as_deref());
+    check_email(&mut violations, "email", self.email.as_deref());
+    violations.into_result()
+  }
+}
+
+impl Validate for Team {
+  fn validate(&self) -> ApiResult<()> {
+    let mut violations = Violations::default();
+    violations.require_non_empty("name", self.name.as_deref());
+    violations.into_result()
+  }
+}
+
+impl Validate for EscalationPolicy {
+  /// Steps after the first must wait longer than zero seconds; the first step
+  /// may run immediately.
+  fn validate(&self) -> ApiResult<()> {
+    let mut violations = Violations::default();
+    violations.require_non_empty("name", Some(&self.name));
+    violations.require_non_empty("teamSlug", Some(&self.team_id));
+
+    for (index, step) in self.steps.iter().enumerate() {
+      if step.timeout < 0 || (index > 0 && step.timeout == 0) {
+        violations.add(
+          format!("steps[{}].timeout", index),
+          format!("must be greater than 0, got {}", step.timeout),
+        );
+      }
+    }
+
+    violations.into_result()
+  }
+}
+
+impl Validate for RoutingKey {
+  fn validate(&self) -> ApiResult<()> {
+    let mut violations = Violations::default();
+
+    match &self.routing_key {
+      None => violations.add("routingKey", "is required"),
+      Some(routing_key) => {
+        if let Some(problem) = routing_key_problem(routing_key) {
+          violations.add("routingKey", problem);
+        }
+      }
+    }
+
+    if self.targets.is_empty() {
+      violations.add("targets", "must contain at least one escalation policy");
+    }
+
+    violations.into_result()
+  }
+}
+
+impl Validate for Contact {
+  fn validate(&self) -> ApiResult<()> {
+    let mut violations = Violations::default();
+
+    if self.contact_type().is_none() {
+      violations.add(
+        "phone",
+        "either phone, email, or a device value is required",
+      );
+    }
+
+    if self.phone_number.is_some() && self.email.is_some() {
+      violations.add("email", "cannot be combined with phone");
+    }
+
+    if let Some(phone) = &self.phone_number
+      && !is_e164(phone)
+    {
+      violations.add(
+        "phone",
+        format!("{:?} is not an E.164 number such as +15555550123", phone),
+      );
+    }
+
+    check_email(&mut violations, "email", self.email.as_deref());
+    violations.into_result()
+  }
+}
+
+impl Validate for TakeRequest {
+  fn validate(&self) -> ApiResult<()> {
+    let mut violations = Violations::default();
+    violations.require_non_empty("fromUser", self.from_user.as_deref());
+    violations.require_non_empty("toUser", self.to_user.as_deref());
+    violations.into_result()
+  }
+}
+
+impl Validate for CreateIncidentRequest {
+  fn validate(&self) -> ApiResult<()> {
+    let mut violations = Violations::default();
+    violations.require_non_empty("summary", Some(&self.summary));
+    violations.require_non_empty("userName", Some(&self.user_name));
+
+    if self.targets.is_empty() {
+      violations.add("targets", "must contain at least one target");
+    }
+
+    violations.into_result()
+  }
+}
+
+impl Validate for IncidentActionRequest {
+  fn validate(&self) -> ApiResult<()> {
+    let mut violations = Violations::default();
+    violations.require_non_empty("userName", Some(&self.user_name));
+
+    if self.incident_names.is_empty() {
+      violations.add("incidentNames", "must contain at least one incident");
+    }
+
+    violations.into_result()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::types::{EscalationPolicyStepEntry, EscalationPolicySteps, IncidentTarget};
+  use serde_json::json;
+
+  fn invalid_input(result: ApiResult<()>) -> String {
+    match result {
+      Err(Error::InvalidInput(message)) => message,
+      other => panic!("expected invalid input, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn test_user_requires_username_and_valid_email() {
+    let user: User = serde_json::from_value(json!({"username": " ", "email": "jdoe"})).unwrap();
+    assert_eq!(
+      invalid_input(user.validate()),
+      "username: must not be empty; email: \"jdoe\" is not an email address"
+    );
+
+    let user: User =
+      serde_json::from_value(json!({"username": "jdoe", "email": "jdoe@example.com"})).unwrap();
+    user.validate().unwrap();
+  }
+
+  #[test]
+  fn test_email_addresses() {
+    for valid in [
+      "jdoe@example.com",
+      "j.doe+alerts@mail.example.co.uk",
+      "o'brien@example.io",
+    ] {
+      assert!(is_email(valid), "{} should be valid", valid);
+    }
+
+    for invalid in [
+      "jdoe",
+      "jdoe@",
+      "@example.com",
+      "jdoe@example",
+      "jdoe@@example.com",
+      "j..doe@example.com",
+      ".jdoe@example.com",
+      "j doe@example.com",
+      "jdoe@-example.com",
+      "jdoe@example..com",
+      "jdoe@example.com.",
+    ] {
+      assert!(!is_email(invalid), "{} should be invalid", invalid);
+    }
+  }
+
+  #[test]
+  fn test_escalation_policy_step_timeouts() {
+    let entries: Vec<EscalationPolicyStepEntry> = vec![
+      serde_json::from_value(json!({"executionType": "user", "user": {"username": "jdoe"}}))
+        .unwrap(),
+    ];
+    let mut policy = EscalationPolicy {
+      name: "Database".to_string(),
+      team_id: "team-db".to_string(),
+      ignore_custom_paging_policies: false,
+      steps: vec![
+        EscalationPolicySteps {
+          timeout: 0,
+          entries: entries.clone(),
+        },
+        EscalationPolicySteps {
+          timeout: 0,
+          entries: entries.clone(),
+        },
+      ],
+      id: String::new(),
+    };
+
+    assert_eq!(
+      invalid_input(policy.validate()),
+      "steps[1].timeout: must be greater than 0, got 0"
+    );
+
+    policy.steps[1].timeout = 15;
+    policy.validate().unwrap();
+  }
+
+  #[test]
+  fn test_routing_key_characters() {
+    let key = RoutingKey {
+      routing_key: Some("db alerts".to_string()),
+      targets: Vec::new(),
+    };
+    assert_eq!(
+      invalid_input(key.validate()),
+      "routingKey: \"db alerts\" contains invalid character ' '; \
+       targets: must contain at least one escalation policy"
+    );
+  }
+
+  #[test]
+  fn test_contact_phone_must_be_e164() {
+    let contact: Contact = serde_json::from_value(json!({"phone": "555-0123"})).unwrap();
+    assert!(invalid_input(contact.validate()).starts_with("phone: \"555-0123\""));
+
+    let contact: Contact = serde_json::from_value(json!({"phone": "+15555550123"})).unwrap();
+    contact.validate().unwrap();
+
+    let contact = Contact {
+      email: Some("jdoe@example.com".to_string()),
+      ..Contact::phone("+15555550123", "Mobile")
+    };
+    assert_eq!(
+      invalid_input(contact.validate()),
+      "email: cannot be combined with phone"
+    );
+
+    assert_eq!(normalize_phone("+1 (555) 555-0123"), "+15555550123");
+    assert!(!is_e164("+0123"));
+    assert!(!is_e164("+1234567890123456"));
+  }
+
+  #[test]
+  fn test_take_request_requires_both_users() {
+    let request = TakeRequest {
+      from_user: Some("jdoe".to_string()),
+      to_user: None,
+    };
+    assert_eq!(invalid_input(request.validate()), "toUser: is required");
+  }
+
+  #[test]
+  fn test_incident_requires_user_and_targets() {
+    let request = CreateIncidentRequest {
+      summary: "Database down".to_string(),
+      details: String::new(),
+      user_name: String::new(),
+      targets: Vec::new(),
+      is_multi_responder: false,
+    };
+    assert_eq!(
+      invalid_input(request.validate()),
+      "userName: must not be empty; targets: must contain at least one target"
+    );
+
+    let request = CreateIncidentRequest {
+      user_name: "jdoe".to_string(),
+      targets: vec![IncidentTarget::user("alice")],
+      ..request
+    };
+    request.validate().unwrap();
+  }
+
+  #[test]
+  fn test_incident_action_requires_incidents() {
+    let request = IncidentActionRequest::new("jdoe", &[], None);
+    assert_eq!(
+      invalid_input(request.validate()),
+      "incidentNames: must contain at least one incident"
+    );
+
+    IncidentActionRequest::new("jdoe", &[42], None)
+      .validate()
+      .unwrap();
+  }
+}
@@ -0,0 +1,232 @@
+use crate::error::{ApiResult, Error};
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Sample webhook payloads for testing downstream handlers.
+#[cfg(feature = "test-util")]
+pub mod fixtures;
+
+#[cfg(feature = "axum")]
+mod extract;
+
+#[cfg(feature = "axum")]
+pub use extract::{VictorOpsWebhook, WebhookRejection, WebhookSecret};
+
+/// The incident fields carried by a VictorOps outgoing webhook.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct WebhookIncident {
+  /// The incident number.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "INCIDENT_NAME")]
+  pub incident_name: Option<String>,
+  /// The entity ID of the alert that opened the incident.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "ENTITY_ID")]
+  pub entity_id: Option<String>,
+  /// A human-friendly name for the entity.
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    rename = "ENTITY_DISPLAY_NAME"
+  )]
+  pub entity_display_name: Option<String>,
+  /// The lifecycle phase of the incident, such as `UNACKED` or `RESOLVED`.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "CURRENT_PHASE")]
+  pub current_phase: Option<String>,
+  /// The routing key the alert was sent to.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "ROUTING_KEY")]
+  pub routing_key: Option<String>,
+  /// The user who acknowledged or resolved the incident.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "ACK_USER")]
+  pub ack_user: Option<String>,
+  /// A verbose description of the alert.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "STATE_MESSAGE")]
+  pub state_message: Option<String>,
+  /// When the incident started.
+  #[serde(skip_serializing_if = "Option::is_none", rename = "INCIDENT_TIMESTAMP")]
+  pub incident_timestamp: Option<String>,
+  /// Any other fields configured in the webhook template.
+  #[serde(flatten)]
+  pub extra: BTreeMap<String, Value>,
+}
+
+/// A VictorOps outgoing webhook, classified by the incident phase it reports.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+  /// A new incident was opened.
+  IncidentTriggered(WebhookIncident),
+  /// An incident was acknowledged.
+  Acknowledged(WebhookIncident),
+  /// An incident was resolved.
+  Resolved(WebhookIncident),
+  /// An incident was escalated to the next step of its policy.
+  Escalated(WebhookIncident),
+  /// A payload that does not describe a known incident phase.
+  Unknown(Value),
+}
+
+impl WebhookEvent {
+  /// Returns the incident fields, unless the event is unknown.
+  pub fn incident(&self) -> Option<&WebhookIncident> {
+    match self {
+      WebhookEvent::IncidentTriggered(incident)
+      | WebhookEvent::Acknowledged(incident)
+      | WebhookEvent::Resolved(incident)
+      | WebhookEvent::Escalated(incident) => Some(incident),
+      WebhookEvent::Unknown(_) => None,
+    }
+  }
+}
+
+/// Parses and classifies a VictorOps outgoing webhook body.
+///
+/// Events are classified by their `CURRENT_PHASE` field. Valid JSON that
+/// does not carry a recognized phase is returned as [`WebhookEvent::Unknown`].
+///
+/// # Arguments
+///
+/// * `body` - The raw webhook request body
+///
+/// # Returns
+///
+/// The classified event, or `Error::Json` if the body is not valid JSON.
+pub fn parse(body: &[u8]) -> ApiResult<WebhookEvent> {
+  let value: Value = serde_json::from_slice(body)?;
+
+  let phase = value
+    .get("CURRENT_PHASE")
+    .and_then(Value::as_str)
+    .map(str::to_ascii_uppercase);
+
+  let make_event: fn(WebhookIncident) -> WebhookEvent = match phase.as_deref() {
+    Some("UNACKED") | Some("TRIGGERED") => WebhookEvent::IncidentTriggered,
+    Some("ACKED") | Some("ACKNOWLEDGED") => WebhookEvent::Acknowledged,
+    Some("RESOLVED") => WebhookEvent::Resolved,
+    Some("ESCALATED") => WebhookEvent::Escalated,
+    _ => return Ok(WebhookEvent::Unknown(value)),
+  };
+
+  match serde_json::from_value::<WebhookIncident>(value.clone()) {
+    Ok(incident) => Ok(make_event(incident)),
+    Err(_) => Ok(WebhookEvent::Unknown(value)),
+  }
+}
+
+/// The header carrying the shared secret configured on the outgoing webhook.
+pub const SECRET_HEADER: &str = "X-VictorOps-Webhook-Secret";
+
+/// Validates and parses an incoming webhook request.
+///
+/// This is the framework-agnostic core of a webhook receiver: pass the
+/// request headers and body from any HTTP server built on the `http` crate.
+///
+/// # Arguments
+///
+/// * `headers` - The request headers
+/// * `body` - The raw request body
+/// * `secret` - The shared secret expected in [`SECRET_HEADER`], if one is configured
+///
+/// # Returns
+///
+/// The classified event, `Error::Authentication` if the secret is missing or
+/// wrong, or `Error::Json` if the body is not valid JSON.
+pub fn parse_request(
+  headers: &HeaderMap,
+  body: &[u8],
+  secret: Option<&str>,
+) -> ApiResult<WebhookEvent> {
+  if let Some(secret) = secret {
+    let provided = headers
+      .get(SECRET_HEADER)
+      .map(|value| value.as_bytes())
+      .unwrap_or_default();
+
+    if !constant_time_eq(provided, secret.as_bytes()) {
+      return Err(Error::Authentication);
+    }
+  }
+
+  parse(body)
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+  if a.len() != b.len() {
+    return false;
+  }
+
+  a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn test_parse_classifies_phases() {
+    let triggered = parse(
+      br#"{"INCIDENT_NAME": "101", "ENTITY_ID": "db-1/disk", "CURRENT_PHASE": "UNACKED", "ROUTING_KEY": "database"}"#,
+    )
+    .unwrap();
+    let WebhookEvent::IncidentTriggered(incident) = &triggered else {
+      panic!("expected triggered event, got {:?}", triggered);
+    };
+    assert_eq!(incident.incident_name, Some("101".to_string()));
+    assert_eq!(incident.routing_key, Some("database".to_string()));
+
+    let acked = parse(br#"{"CURRENT_PHASE": "ACKED", "ACK_USER": "jdoe"}"#).unwrap();
+    assert!(matches!(acked, WebhookEvent::Acknowledged(_)));
+    assert_eq!(
+      acked.incident().and_then(|i| i.ack_user.clone()),
+      Some("jdoe".to_string())
+    );
+
+    let resolved = parse(br#"{"CURRENT_PHASE": "resolved"}"#).unwrap();
+    assert!(matches!(resolved, WebhookEvent::Resolved(_)));
+
+    let escalated = parse(br#"{"CURRENT_PHASE": "ESCALATED", "TEAM": "ops"}"#).unwrap();
+    let incident = escalated.incident().unwrap();
+    assert_eq!(incident.extra.get("TEAM"), Some(&json!("ops")));
+  }
+
+  #[test]
+  fn test_parse_unknown_and_invalid() {
+    let unknown = parse(br#"{"message": "custom template"}"#).unwrap();
+    assert_eq!(
+      unknown,
+      WebhookEvent::Unknown(json!({"message": "custom template"}))
+    );
+    assert!(unknown.incident().is_none());
+
+    let unrecognized_phase = parse(br#"{"CURRENT_PHASE": "SNOOZED"}"#).unwrap();
+    assert!(matches!(unrecognized_phase, WebhookEvent::Unknown(_)));
+
+    assert!(matches!(parse(b"not json"), Err(Error::Json(_))));
+  }
+
+  #[test]
+  fn test_parse_request_validates_secret() {
+    let body = br#"{"CURRENT_PHASE": "UNACKED"}"#;
+
+    let mut headers = HeaderMap::new();
+    assert!(matches!(
+      parse_request(&headers, body, Some("s3cret")),
+      Err(Error::Authentication)
+    ));
+
+    headers.insert(SECRET_HEADER, "wrong".parse().unwrap());
+    assert!(matches!(
+      parse_request(&headers, body, Some("s3cret")),
+      Err(Error::Authentication)
+    ));
+
+    headers.insert(SECRET_HEADER, "s3cret".parse().unwrap());
+    assert!(matches!(
+      parse_request(&headers, body, Some("s3cret")),
+      Ok(WebhookEvent::IncidentTriggered(_))
+    ));
+
+    assert!(parse_request(&HeaderMap::new(), body, None).is_ok());
+  }
+}
@@ -0,0 +1,123 @@
+use super::{WebhookEvent, parse_request};
+use crate::error::Error;
+use axum::body::Bytes;
+use axum::extract::rejection::BytesRejection;
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+/// The shared secret a webhook route expects in the
+/// [`SECRET_HEADER`](super::SECRET_HEADER).
+///
+/// [`VictorOpsWebhook`] reads it from the router state through `FromRef`.
+/// `WebhookSecret(None)` accepts requests without checking the header.
+#[derive(Debug, Clone, Default)]
+pub struct WebhookSecret(pub Option<String>);
+
+/// An axum extractor that validates and parses a VictorOps outgoing webhook.
+///
+/// The request body is passed to [`parse_request`] along with the
+/// [`WebhookSecret`] taken from the router state.
+///
+/// # Examples
+///
+/// ```no_run
+/// use axum::Router;
+/// use axum::routing::post;
+/// use victorops::webhook::{VictorOpsWebhook, WebhookSecret};
+///
+/// async fn handle(VictorOpsWebhook(event): VictorOpsWebhook) {
+///   println!("{:?}", event);
+/// }
+///
+/// let app: Router = Router::new()
+///   .route("/victorops", post(handle))
+///   .with_state(WebhookSecret(Some("shared-secret".to_string())));
+/// ```
+#[derive(Debug, Clone)]
+pub struct VictorOpsWebhook(pub WebhookEvent);
+
+/// Why a [`VictorOpsWebhook`] could not be extracted.
+#[derive(Debug)]
+pub enum WebhookRejection {
+  /// The request body could not be read.
+  Body(BytesRejection),
+  /// The secret was missing or wrong, or the body was not valid JSON.
+  Invalid(Error),
+}
+
+impl IntoResponse for WebhookRejection {
+  fn into_response(self) -> Response {
+    match self {
+      WebhookRejection::Body(rejection) => rejection.into_response(),
+      WebhookRejection::Invalid(Error::Authentication) => StatusCode::UNAUTHORIZED.into_response(),
+      WebhookRejection::Invalid(error) => {
+        (StatusCode::BAD_REQUEST, error.to_string()).into_response()
+      }
+    }
+  }
+}
+
+impl<S> FromRequest<S> for VictorOpsWebhook
+where
+  S: Send + Sync,
+  WebhookSecret: FromRef<S>,
+{
+  type Rejection = WebhookRejection;
+
+  async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+    let WebhookSecret(secret) = WebhookSecret::from_ref(state);
+    let headers = request.headers().clone();
+    let body = Bytes::from_request(request, state)
+      .await
+      .map_err(WebhookRejection::Body)?;
+
+    parse_request(&headers, &body, secret.as_deref())
+      .map(VictorOpsWebhook)
+      .map_err(WebhookRejection::Invalid)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::webhook::SECRET_HEADER;
+  use axum::body::Body;
+
+  fn request(secret: Option<&str>, body: &'static str) -> Request {
+    let mut builder = Request::builder().method("POST").uri("/victorops");
+    if let Some(secret) = secret {
+      builder = builder.header(SECRET_HEADER, secret);
+    }
+    builder.body(Body::from(body)).unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_extractor_checks_secret_from_state() {
+    let state = WebhookSecret(Some("s3cret".to_string()));
+    let body = r#"{"CURRENT_PHASE": "RESOLVED", "INCIDENT_NAME": "42"}"#;
+
+    let VictorOpsWebhook(event) =
+      VictorOpsWebhook::from_request(request(Some("s3cret"), body), &state)
+        .await
+        .unwrap();
+    assert!(matches!(event, WebhookEvent::Resolved(_)));
+
+    let rejection = VictorOpsWebhook::from_request(request(Some("wrong"), body), &state)
+      .await
+      .unwrap_err();
+    assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED);
+
+    let rejection = VictorOpsWebhook::from_request(request(Some("s3cret"), "not json"), &state)
+      .await
+      .unwrap_err();
+    assert_eq!(rejection.into_response().status(), StatusCode::BAD_REQUEST);
+
+    let open = WebhookSecret(None);
+    assert!(
+      VictorOpsWebhook::from_request(request(None, body), &open)
+        .await
+        .is_ok()
+    );
+  }
+}
@@ -0,0 +1,88 @@
+//! Realistic outgoing webhook payloads for testing webhook handlers.
+//!
+//! Each fixture is a raw request body as VictorOps would send it, suitable
+//! for passing straight to [`parse`](super::parse) or to a handler under test.
+
+/// A webhook for a newly opened incident.
+pub const INCIDENT_TRIGGERED: &str = r#"{
+  "INCIDENT_NAME": "1042",
+  "ENTITY_ID": "db-1.prod:disk",
+  "ENTITY_DISPLAY_NAME": "db-1 disk usage",
+  "CURRENT_PHASE": "UNACKED",
+  "ROUTING_KEY": "database",
+  "STATE_MESSAGE": "/var is 98% full",
+  "INCIDENT_TIMESTAMP": "2024-01-01T08:00:00Z",
+  "ALERT_COUNT": 1,
+  "MONITORING_TOOL": "prometheus"
+}"#;
+
+/// A webhook for an acknowledged incident.
+pub const ACKNOWLEDGED: &str = r#"{
+  "INCIDENT_NAME": "1042",
+  "ENTITY_ID": "db-1.prod:disk",
+  "ENTITY_DISPLAY_NAME": "db-1 disk usage",
+  "CURRENT_PHASE": "ACKED",
+  "ROUTING_KEY": "database",
+  "ACK_USER": "jdoe",
+  "ACK_MSG": "Looking into it",
+  "INCIDENT_TIMESTAMP": "2024-01-01T08:00:00Z"
+}"#;
+
+/// A webhook for a resolved incident.
+pub const RESOLVED: &str = r#"{
+  "INCIDENT_NAME": "1042",
+  "ENTITY_ID": "db-1.prod:disk",
+  "ENTITY_DISPLAY_NAME": "db-1 disk usage",
+  "CURRENT_PHASE": "RESOLVED",
+  "ROUTING_KEY": "database",
+  "ACK_USER": "jdoe",
+  "STATE_MESSAGE": "/var is 61% full",
+  "INCIDENT_TIMESTAMP": "2024-01-01T08:00:00Z"
+}"#;
+
+/// A webhook for an incident escalated to the next policy step.
+pub const ESCALATED: &str = r#"{
+  "INCIDENT_NAME": "1042",
+  "ENTITY_ID": "db-1.prod:disk",
+  "ENTITY_DISPLAY_NAME": "db-1 disk usage",
+  "CURRENT_PHASE": "ESCALATED",
+  "ROUTING_KEY": "database",
+  "ESCALATION_POLICY": "database-primary",
+  "INCIDENT_TIMESTAMP": "2024-01-01T08:00:00Z"
+}"#;
+
+/// A webhook built from a custom template that carries no incident phase.
+pub const UNKNOWN: &str = r#"{
+  "text": "Custom webhook template",
+  "channel": "ops-alerts"
+}"#;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::webhook::{WebhookEvent, parse};
+
+  #[test]
+  fn test_fixtures_parse_to_their_event() {
+    assert!(matches!(
+      parse(INCIDENT_TRIGGERED.as_bytes()).unwrap(),
+      WebhookEvent::IncidentTriggered(_)
+    ));
+    assert!(matches!(
+      parse(ACKNOWLEDGED.as_bytes()).unwrap(),
+      WebhookEvent::Acknowledged(_)
+    ));
+    assert!(matches!(
+      parse(RESOLVED.as_bytes()).unwrap(),
+      WebhookEvent::Resolved(_)
+    ));
+    assert!(matches!(
+      parse(ESCALATED.as_bytes()).unwrap(),
+      WebhookEvent::Escalated(_)
+    ));
+    assert!(matches!(
+      parse(UNKNOWN.as_bytes()).unwrap(),
+      WebhookEvent::Unknown(_)
+    ));
+  }
+}